@@ -1,7 +1,10 @@
 use std::io::Read;
 
 use anyhow::{Context, Result};
-use bushing_solver::{compute_bushing, BushingInputRaw};
+use bushing_solver::{
+    bushing_sensitivity_with, canonical, migrate, schema, compute_bushing_with, size_bushing_with, sweep_bushing_with, BushingError,
+    BushingInputRaw, InputMode, MaterialLibrary, RuleBook, SizingRequest, SweepRequest,
+};
 use clap::Parser;
 
 #[derive(Parser)]
@@ -15,18 +18,73 @@ struct Args {
     #[arg(short, long)]
     input: Option<std::path::PathBuf>,
 
-    /// Output format: json (default), compact
+    /// Output format: json (default), compact, csv (sweep tables only)
     #[arg(short, long, default_value = "json")]
     format: String,
 
     /// Pretty-print indent (ignored for compact format)
     #[arg(long, default_value = "2")]
     indent: usize,
+
+    /// Approval rule file layered over the built-in rule sets
+    #[arg(long)]
+    rules: Option<std::path::PathBuf>,
+
+    /// Material library (JSON or CSV) layered over the built-in materials
+    #[arg(long)]
+    materials: Option<std::path::PathBuf>,
+
+    /// Sweep request file; the input becomes the base case of the grid
+    #[arg(long)]
+    sweep: Option<std::path::PathBuf>,
+
+    /// Sizing request file; search bore, wall and interference around the input
+    #[arg(long)]
+    size: Option<std::path::PathBuf>,
+
+    /// Reject unknown keys, invalid enum values and conflicting aliases
+    /// instead of reporting them as warnings
+    #[arg(long)]
+    strict: bool,
+
+    /// Upgrade the input to the current schema version and print it with a
+    /// report of the changes instead of solving
+    #[arg(long)]
+    migrate: bool,
+
+    /// Print the normalized input as the canonical camelCase document
+    /// (defaults filled in, current schema version) instead of solving
+    #[arg(long)]
+    canonical: bool,
+
+    /// Print the JSON Schema of the current input version and exit
+    #[arg(long)]
+    emit_schema: bool,
+
+    /// Report input sensitivities of the key outputs instead of the full solve
+    #[arg(long)]
+    sensitivity: bool,
+}
+
+/// Solver failures as CLI errors; validation findings are also written to
+/// stdout as JSON so callers can highlight every offending field.
+fn solver_error(e: BushingError) -> anyhow::Error {
+    if !e.issues().is_empty() {
+        if let Ok(json) = serde_json::to_string_pretty(&serde_json::json!({ "issues": e.issues() })) {
+            println!("{json}");
+        }
+    }
+    anyhow::anyhow!("{e}")
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
+    if args.emit_schema {
+        println!("{}", serde_json::to_string_pretty(&schema::json_schema()).context("serialising schema")?);
+        return Ok(());
+    }
+
     let raw_json = match args.input {
         Some(path) => std::fs::read_to_string(&path)
             .with_context(|| format!("reading input file {}", path.display()))?,
@@ -41,9 +99,76 @@ fn main() -> Result<()> {
 
     let value: serde_json::Value =
         serde_json::from_str(&raw_json).context("parsing input JSON")?;
-    let raw = BushingInputRaw(value);
 
-    let output = compute_bushing(raw).map_err(|e| anyhow::anyhow!("{e}"))?;
+    if args.migrate {
+        let (document, report) = migrate::migrate(value).map_err(solver_error)?;
+        let migrated = serde_json::json!({ "document": document, "report": report });
+        println!("{}", serde_json::to_string_pretty(&migrated).context("serialising migrated input")?);
+        return Ok(());
+    }
+
+    let raw  = BushingInputRaw(value);
+    let mode = if args.strict { InputMode::Strict } else { InputMode::Lenient };
+
+    if args.canonical {
+        let (input, _) = raw.normalize_with(mode).map_err(solver_error)?;
+        let document   = canonical::export(&input).map_err(solver_error)?;
+        let out_str    = match args.format.as_str() {
+            "compact" => serde_json::to_string(&document).context("serialising input")?,
+            _ => serde_json::to_string_pretty(&document).context("serialising input")?,
+        };
+        println!("{out_str}");
+        return Ok(());
+    }
+
+    // Every solving path below uses the same rules, materials and input mode
+    let mut rules = RuleBook::builtin().clone();
+    if let Some(path) = &args.rules {
+        rules.merge(RuleBook::load(path).map_err(solver_error)?);
+    }
+
+    let mut library = MaterialLibrary::builtin().clone();
+    if let Some(path) = &args.materials {
+        library.merge(MaterialLibrary::load(path).map_err(solver_error)?);
+    }
+
+    if let Some(path) = &args.sweep {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("reading sweep file {}", path.display()))?;
+        let request: SweepRequest = serde_json::from_str(&text).context("parsing sweep request")?;
+        let table = sweep_bushing_with(raw, &request, mode, &rules, &library).map_err(solver_error)?;
+        match args.format.as_str() {
+            "csv"     => print!("{}", table.to_csv()),
+            "compact" => println!("{}", serde_json::to_string(&table).context("serialising table")?),
+            _         => println!("{}", serde_json::to_string_pretty(&table).context("serialising table")?),
+        }
+        return Ok(());
+    }
+
+    if let Some(path) = &args.size {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("reading sizing file {}", path.display()))?;
+        let request: SizingRequest = serde_json::from_str(&text).context("parsing sizing request")?;
+        let result = size_bushing_with(raw, &request, mode, &rules, &library).map_err(solver_error)?;
+        let out_str = match args.format.as_str() {
+            "compact" => serde_json::to_string(&result).context("serialising sizing result")?,
+            _ => serde_json::to_string_pretty(&result).context("serialising sizing result")?,
+        };
+        println!("{out_str}");
+        return Ok(());
+    }
+
+    if args.sensitivity {
+        let report = bushing_sensitivity_with(raw, mode, &rules, &library).map_err(solver_error)?;
+        let out_str = match args.format.as_str() {
+            "compact" => serde_json::to_string(&report).context("serialising report")?,
+            _ => serde_json::to_string_pretty(&report).context("serialising report")?,
+        };
+        println!("{out_str}");
+        return Ok(());
+    }
+
+    let output = compute_bushing_with(raw, mode, &rules, &library).map_err(solver_error)?;
 
     let out_str = match args.format.as_str() {
         "compact" => serde_json::to_string(&output).context("serialising output")?,
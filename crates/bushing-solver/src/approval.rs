@@ -0,0 +1,322 @@
+/// Rule-driven approval review keyed on standards basis and criticality.
+/// Replaces the fixed review logic in serviceAnalysis.ts with data-file rule sets.
+use std::{path::Path, sync::OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::BushingError, types::*};
+
+/// Built-in rule sets shipped with the solver.
+const BUILTIN_RULES: &str = include_str!("../data/approval_rules.json");
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    fn holds(self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Comparison::Lt => lhs < rhs,
+            Comparison::Le => lhs <= rhs,
+            Comparison::Gt => lhs > rhs,
+            Comparison::Ge => lhs >= rhs,
+        }
+    }
+}
+
+/// Computed quantities a rule can compare against.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalMetric {
+    GoverningMargin,
+    HousingMargin,
+    BushingMargin,
+    PvUtilization,
+    /// Edge distance over bore diameter (e/D).
+    EdgeDistanceRatio,
+    InstallForce,
+    RetainedInstallForce,
+    /// Lowest effective interference across the installed service states.
+    MinServiceInterference,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RuleCondition {
+    Always,
+    Metric { metric: ApprovalMetric, op: Comparison, value: f64 },
+    Warning { code: BushingWarningCode },
+    WearRisk { at_least: WearRisk },
+    FinishMachining,
+    ServiceClearance,
+    Route { routes: Vec<BushingProcessRouteId> },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalRule {
+    pub id: String,
+    /// Criticalities the rule applies to; empty applies to all.
+    #[serde(default)]
+    pub criticality: Vec<BushingCriticality>,
+    pub when: RuleCondition,
+    pub outcome: ApprovalDecision,
+    pub message: String,
+}
+
+/// A group of rules. Sets without a `basis` apply to every standards basis.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleSet {
+    pub id: String,
+    #[serde(default)]
+    pub basis: Option<BushingStandardsBasis>,
+    #[serde(default)]
+    pub revision: Option<String>,
+    #[serde(default)]
+    pub refs: Vec<String>,
+    #[serde(default)]
+    pub assumptions: Vec<String>,
+    #[serde(default)]
+    pub rules: Vec<ApprovalRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleBook {
+    pub version: u32,
+    pub rule_sets: Vec<RuleSet>,
+}
+
+impl RuleBook {
+    pub fn from_json(text: &str) -> Result<Self, BushingError> {
+        Ok(serde_json::from_str(text)?)
+    }
+
+    /// Load a rule book from a JSON data file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BushingError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| BushingError::InvalidInput(format!("reading rule file {}: {e}", path.display())))?;
+        Self::from_json(&text)
+    }
+
+    /// The rule sets shipped with the solver.
+    pub fn builtin() -> &'static RuleBook {
+        static BOOK: OnceLock<RuleBook> = OnceLock::new();
+        BOOK.get_or_init(|| RuleBook::from_json(BUILTIN_RULES).expect("built-in approval rules parse"))
+    }
+
+    /// Layer `other` on top of this book. A set with an existing `id` has its
+    /// rules appended and its revision / refs replaced when given; new ids are added.
+    pub fn merge(&mut self, other: RuleBook) {
+        for set in other.rule_sets {
+            match self.rule_sets.iter_mut().find(|s| s.id == set.id) {
+                Some(existing) => {
+                    if set.revision.is_some() {
+                        existing.revision = set.revision;
+                    }
+                    if !set.refs.is_empty() {
+                        existing.refs = set.refs;
+                    }
+                    existing.assumptions.extend(set.assumptions);
+                    existing.rules.extend(set.rules);
+                }
+                None => self.rule_sets.push(set),
+            }
+        }
+    }
+
+    fn sets_for(&self, basis: BushingStandardsBasis) -> impl Iterator<Item = &RuleSet> {
+        self.rule_sets.iter().filter(move |s| s.basis.is_none_or(|b| b == basis))
+    }
+}
+
+fn metric_value(metric: ApprovalMetric, input: &BushingInput, out: &BushingOutput) -> f64 {
+    match metric {
+        ApprovalMetric::GoverningMargin      => out.governing.margin,
+        ApprovalMetric::HousingMargin        => out.hoop.housing_ms,
+        ApprovalMetric::BushingMargin        => out.hoop.bushing_ms,
+        ApprovalMetric::PvUtilization        => out.duty_screen.pv_utilization,
+        ApprovalMetric::EdgeDistanceRatio    => input.edge_dist / out.lame.bore_dia,
+        ApprovalMetric::InstallForce         => out.physics.install_force,
+        ApprovalMetric::RetainedInstallForce => out.physics.retained_install_force,
+        ApprovalMetric::MinServiceInterference => out
+            .service_envelope
+            .states
+            .iter()
+            .filter(|st| st.id != "free")
+            .map(|st| st.effective_interference)
+            .fold(f64::INFINITY, f64::min),
+    }
+}
+
+fn condition_holds(cond: &RuleCondition, input: &BushingInput, out: &BushingOutput) -> bool {
+    match cond {
+        RuleCondition::Always => true,
+        RuleCondition::Metric { metric, op, value } => op.holds(metric_value(*metric, input, out), *value),
+        RuleCondition::Warning { code } => out.warning_codes.iter().any(|w| w.code == *code),
+        RuleCondition::WearRisk { at_least } => out.duty_screen.wear_risk >= *at_least,
+        RuleCondition::FinishMachining => out.process.finish_machining_required,
+        RuleCondition::ServiceClearance => out
+            .service_envelope
+            .states
+            .iter()
+            .any(|st| st.id != "free" && st.fit_class == "clearance"),
+        RuleCondition::Route { routes } => routes.contains(&input.process_route_id),
+    }
+}
+
+fn criticality_hint(c: BushingCriticality) -> &'static str {
+    match c {
+        BushingCriticality::General          => "General hardware with local engineering review.",
+        BushingCriticality::PrimaryStructure => "Primary structure or critical joint; approval trail should be explicit.",
+        BushingCriticality::Repair           => "Repair scenario; authority and rework basis should be captured.",
+    }
+}
+
+/// Evaluate the rule book against the assembled output.
+pub fn build_approval_review(input: &BushingInput, out: &BushingOutput, book: &RuleBook) -> ApprovalReviewResult {
+    let basis = input.standards_basis;
+    let sets: Vec<&RuleSet> = book.sets_for(basis).collect();
+
+    let mut findings = vec![];
+    for set in &sets {
+        for rule in &set.rules {
+            let applies = rule.criticality.is_empty() || rule.criticality.contains(&input.criticality);
+            if applies && condition_holds(&rule.when, input, out) {
+                findings.push(ApprovalFinding {
+                    rule_id: format!("{}.{}", set.id, rule.id),
+                    outcome: rule.outcome,
+                    message: rule.message.clone(),
+                });
+            }
+        }
+    }
+    let decision = findings.iter().map(|f| f.outcome).max().unwrap_or(ApprovalDecision::Pass);
+
+    let revision = input
+        .standards_revision
+        .clone()
+        .filter(|r| !r.trim().is_empty())
+        .or_else(|| sets.iter().rev().find_map(|s| s.revision.clone()))
+        .unwrap_or_else(|| "current".into());
+    let process_spec = input
+        .process_spec
+        .clone()
+        .filter(|s| !s.trim().is_empty())
+        .unwrap_or_else(|| out.process.route_label.clone());
+    let notes = input.approval_notes.as_deref().map(str::trim).filter(|n| !n.is_empty());
+
+    let mut traceability_refs: Vec<String> = sets.iter().flat_map(|s| s.refs.iter().cloned()).collect();
+    traceability_refs.push(format!("Process route: {}", out.process.route_label));
+    traceability_refs.push(format!("Process spec: {process_spec}"));
+    traceability_refs.push(format!("Standards revision: {revision}"));
+    traceability_refs.push(format!("Criticality: {}", enum_label(&input.criticality)));
+    if let Some(n) = notes {
+        traceability_refs.push(format!("Approval notes recorded: {}", n.chars().take(120).collect::<String>()));
+    }
+    traceability_refs.push(format!("Service envelope governed by {}", out.service_envelope.governing_state_label));
+    traceability_refs.push(format!("Duty screen risk: {}", enum_label(&out.duty_screen.wear_risk)));
+    traceability_refs.extend(findings.iter().map(|f| format!("Rule {}: {}", f.rule_id, f.message)));
+
+    let mut assumptions: Vec<String> = sets.iter().flat_map(|s| s.assumptions.iter().cloned()).collect();
+    assumptions.push(format!("Guidance basis: {}", criticality_hint(input.criticality)));
+    assumptions.push(format!(
+        "Surface finish target: Ra {} µm with roundness target {} µm.",
+        out.process.recommended_ra_um, out.process.roundness_target_um
+    ));
+    assumptions.push(match notes {
+        Some(n) => format!("Approval notes captured: {n}"),
+        None    => "No approval notes were provided.".into(),
+    });
+
+    ApprovalReviewResult {
+        standards_basis: basis,
+        standards_revision: revision,
+        process_spec,
+        criticality: input.criticality,
+        approval_required: decision != ApprovalDecision::Pass,
+        decision,
+        traceability_refs,
+        assumptions,
+        findings,
+    }
+}
+
+/// `ApprovalReviewRequired` warning for a review that did not pass.
+pub fn review_warning(review: &ApprovalReviewResult) -> Option<BushingWarning> {
+    let (severity, verb) = match review.decision {
+        ApprovalDecision::Pass        => return None,
+        ApprovalDecision::Conditional => (WarningSeverity::Warning, "requires approval"),
+        ApprovalDecision::Reject      => (WarningSeverity::Error, "rejected"),
+    };
+    Some(BushingWarning {
+        code: BushingWarningCode::ApprovalReviewRequired,
+        message: format!("Approval review {verb}: {} rule(s) fired.", review.findings.len()),
+        severity,
+    })
+}
+
+/// snake_case serde name of a unit enum, for human-readable refs.
+fn enum_label<T: Serialize>(v: &T) -> String {
+    serde_json::to_value(v).ok().and_then(|v| v.as_str().map(String::from)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{solver, test_support::input_with};
+    use serde_json::json;
+
+    fn solve_with(extra: serde_json::Value, book: &RuleBook) -> BushingOutput {
+        solver::compute_with_rules(&input_with(extra), book).unwrap()
+    }
+
+    fn solve(extra: serde_json::Value) -> BushingOutput {
+        solve_with(extra, RuleBook::builtin())
+    }
+
+    #[test]
+    fn shop_default_general_passes() {
+        let out = solve(json!({}));
+        assert_eq!(out.review.decision, ApprovalDecision::Pass, "{:?}", out.review.findings);
+        assert!(!out.review.approval_required);
+        assert!(out.review.traceability_refs.contains(&"Internal process standard".to_string()));
+        assert!(!out.warning_codes.iter().any(|w| w.code == BushingWarningCode::ApprovalReviewRequired));
+    }
+
+    #[test]
+    fn faa_basis_is_conditional_and_flags_review() {
+        let out = solve(json!({ "standardsBasis": "faa_ac_43_13" }));
+        assert_eq!(out.review.decision, ApprovalDecision::Conditional);
+        assert_eq!(out.review.standards_revision, "AC 43.13-1B");
+        assert!(out.review.findings.iter().any(|f| f.rule_id == "faa_ac_43_13.ac43_inspection"));
+        assert!(out.warning_codes.iter().any(|w| w.code == BushingWarningCode::ApprovalReviewRequired));
+    }
+
+    #[test]
+    fn negative_margin_rejects() {
+        let out = solve(json!({ "interference": 0.02, "criticality": "primary_structure" }));
+        assert_eq!(out.review.decision, ApprovalDecision::Reject);
+        assert!(out.review.findings.iter().any(|f| f.rule_id == "common.negative_margin"));
+    }
+
+    #[test]
+    fn shop_rules_merge_from_data() {
+        let mut book = RuleBook::builtin().clone();
+        book.merge(
+            RuleBook::from_json(
+                r#"{ "version": 1, "rule_sets": [ { "id": "shop_default", "refs": ["Shop QP-12"], "rules": [
+                    { "id": "force_cap", "when": { "type": "metric", "metric": "install_force", "op": "gt", "value": 500 },
+                      "outcome": "conditional", "message": "Install force exceeds the shop arbor press." } ] } ] }"#,
+            )
+            .unwrap(),
+        );
+        let out = solve_with(json!({}), &book);
+        assert_eq!(out.review.decision, ApprovalDecision::Conditional);
+        assert!(out.review.traceability_refs.contains(&"Shop QP-12".to_string()));
+    }
+}
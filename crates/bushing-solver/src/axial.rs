@@ -0,0 +1,117 @@
+/// Axial stress from end constraint and engaged length (generalized plane strain),
+/// and the surface stress states the hoop margins are taken against.
+/// Constraint and length factors ported from solveEngine.ts (axial section).
+use crate::{criteria, math, types::*};
+
+#[derive(Debug, Clone, Copy)]
+pub struct AxialModel {
+    /// 0 free, 0.5 one end, 1 both ends.
+    pub constraint_factor: f64,
+    /// Engaged length over four walls, clamped to [0, 1]; short housings
+    /// cannot develop the full constrained axial stress.
+    pub length_factor: f64,
+}
+
+impl AxialModel {
+    pub fn new(end: EndConstraint, housing_len: f64, wall: f64) -> Self {
+        let constraint_factor = match end {
+            EndConstraint::Free     => 0.0,
+            EndConstraint::OneEnd   => 0.5,
+            EndConstraint::BothEnds => 1.0,
+        };
+        let length_factor = (housing_len / (4.0 * wall).max(1e-6)).clamp(0.0, 1.0);
+        Self { constraint_factor, length_factor }
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.constraint_factor * self.length_factor
+    }
+
+    /// Axial stress for a radial / hoop pair. Full scale is plane strain,
+    /// σz = ν(σr + σθ); zero scale is plane stress.
+    pub fn sigma_axial(&self, nu: f64, sigma_r: f64, sigma_theta: f64) -> f64 {
+        self.scale() * nu * (sigma_r + sigma_theta)
+    }
+
+    pub fn label(&self) -> &'static str {
+        if self.scale() > 0.0 { "generalized_plane_strain" } else { "plane_stress" }
+    }
+}
+
+/// Stress state at the critical (inner) surface of a region.
+#[derive(Debug, Clone, Copy)]
+pub struct SurfaceState {
+    pub sigma_r: f64,
+    pub sigma_theta: f64,
+    pub sigma_axial: f64,
+}
+
+impl SurfaceState {
+    pub fn equivalent(&self, criterion: FailureCriterion) -> f64 {
+        criteria::equivalent(criterion, self.sigma_r, self.sigma_theta, self.sigma_axial)
+    }
+}
+
+/// Housing bore surface under contact pressure `p`.
+pub fn housing_surface(model: &AxialModel, nu: f64, bore_r: f64, outer_r: f64, p: f64) -> SurfaceState {
+    let sigma_r     = -p;
+    let sigma_theta = math::lame_hoop_stress(bore_r, outer_r, p, bore_r);
+    SurfaceState { sigma_r, sigma_theta, sigma_axial: model.sigma_axial(nu, sigma_r, sigma_theta) }
+}
+
+/// Bushing ID surface under external contact pressure `p`.
+pub fn bushing_surface(model: &AxialModel, nu: f64, id_r: f64, bore_r: f64, p: f64) -> SurfaceState {
+    let (sigma_r, sigma_theta) = math::lame_external_stresses(id_r, bore_r, p, id_r);
+    SurfaceState { sigma_r, sigma_theta, sigma_axial: model.sigma_axial(nu, sigma_r, sigma_theta) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::solve_with;
+    use serde_json::json;
+
+    #[test]
+    fn free_ends_are_plane_stress() {
+        let out = solve_with(json!({}));
+        assert_eq!(out.physics.axial_constraint_factor, 0.0);
+        assert_eq!(out.physics.stress_axial_housing, 0.0);
+        assert_eq!(out.lame.field.axial_model, "plane_stress");
+        assert!(out.lame.field.housing.samples.iter().all(|s| s.sigma_axial == 0.0));
+    }
+
+    #[test]
+    fn both_ends_is_plane_strain_through_the_field() {
+        let out = solve_with(json!({ "endConstraint": "both_ends" }));
+        assert_eq!(out.physics.axial_constraint_factor, 1.0);
+        assert_eq!(out.physics.axial_length_factor, 1.0);
+        assert_eq!(out.lame.field.axial_model, "generalized_plane_strain");
+        // 7075 housing: σz = ν(σr + σθ), uniform across the Lamé field
+        for s in &out.lame.field.housing.samples {
+            assert!((s.sigma_axial - 0.33 * (s.sigma_r + s.sigma_theta)).abs() < 1e-9);
+        }
+        assert!(out.physics.stress_axial_housing > 0.0);
+        assert!(out.physics.stress_axial_bushing < 0.0);
+    }
+
+    #[test]
+    fn short_housing_and_one_end_scale_axial_stress() {
+        let full  = solve_with(json!({ "endConstraint": "both_ends" }));
+        let one   = solve_with(json!({ "endConstraint": "one_end" }));
+        let short = solve_with(json!({ "endConstraint": "both_ends", "housingLen": 0.25 }));
+        assert!((one.physics.stress_axial_housing - 0.5 * full.physics.stress_axial_housing).abs() < 1e-9);
+        assert!((short.physics.axial_length_factor - 0.25 / 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn margins_use_von_mises_equivalent() {
+        let out = solve_with(json!({ "endConstraint": "both_ends" }));
+        let p   = out.pressure;
+        let b   = out.lame.field.housing.samples[0].clone();
+        let vm  = crate::math::von_mises(b.sigma_r, b.sigma_theta, b.sigma_axial);
+        assert!((b.sigma_r + p).abs() < 1e-9);
+        assert!((out.hoop.housing_sigma_eq - vm).abs() < 1e-9);
+        assert!((out.hoop.housing_ms - (73.0 / vm - 1.0)).abs() < 1e-9);
+        let bushing_id = &out.lame.field.bushing.samples[0];
+        assert!(bushing_id.sigma_r.abs() < 1e-9 && bushing_id.sigma_theta < 0.0);
+    }
+}
@@ -0,0 +1,95 @@
+/// Pin load path through the bushing: pin bearing at the bushing ID,
+/// bushing-to-housing bearing, and lug net-tension and shear-out, each
+/// combined with the interference prestress where it acts on the same plane.
+use crate::{criteria::margin, ligament, types::*};
+
+/// Shear-out plane angle measured from the load line (classic lug practice).
+const SHEAR_OUT_ANGLE_DEG: f64 = 40.0;
+
+fn check(load_kips: f64, area: f64, prestress: f64, allowable: f64) -> BearingCheck {
+    let stress = if area > 0.0 { load_kips / area + prestress } else { prestress };
+    BearingCheck { area, stress, allowable, margin: margin(allowable, stress) }
+}
+
+/// Evaluate the bearing path for an entered pin `load`. `pressure` is the
+/// fit contact pressure and `housing_hoop` the housing hoop stress at the bore.
+pub fn build_bearing(
+    input: &BushingInput,
+    mat_h: &MaterialProps,
+    mat_b: &MaterialProps,
+    bore_dia: f64,
+    pressure: f64,
+    housing_hoop: f64,
+) -> Option<BearingResult> {
+    let load = input.load?.abs();
+    let kips = load / 1000.0;
+    let edge = ligament::edge_load_kips(input);
+    let t    = input.housing_len;
+
+    // Projected bearing areas; the interface also carries the fit pressure
+    let pin_bearing             = check(kips, input.id_bushing * t, 0.0, mat_b.fbru_ksi);
+    let bushing_housing_bearing = check(kips, bore_dia * t, pressure, mat_h.fbru_ksi.min(mat_b.fbru_ksi));
+
+    // Net section across the hole, loaded on top of the hoop prestress
+    let net_area    = (input.housing_width - bore_dia).max(0.0) * t;
+    let net_tension = check(edge, net_area, housing_hoop, mat_h.ftu_ksi.unwrap_or(mat_h.sy_ksi));
+
+    // Two shear planes from the bore to the edge
+    let plane     = input.edge_dist - bore_dia / 2.0 * SHEAR_OUT_ANGLE_DEG.to_radians().cos();
+    let shear_out = check(edge, 2.0 * plane.max(0.0) * t, 0.0, mat_h.fsu_ksi);
+
+    Some(BearingResult {
+        load,
+        edge_load: edge * 1000.0,
+        pin_bearing,
+        bushing_housing_bearing,
+        net_tension,
+        shear_out,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::solve_with;
+    use serde_json::json;
+
+    #[test]
+    fn no_load_no_bearing_checks() {
+        let out = solve_with(json!({}));
+        assert!(out.bearing.is_none());
+        assert!(!out.candidates.iter().any(|c| c.name == "pin_bearing"));
+    }
+
+    #[test]
+    fn bearing_stresses_from_pin_load() {
+        let out = solve_with(json!({ "load": 3000.0 }));
+        let b   = out.bearing.as_ref().unwrap();
+        assert!((b.pin_bearing.stress - 3.0 / (0.25 * 0.75)).abs() < 1e-9);
+        assert_eq!(b.pin_bearing.allowable, 290.0);
+        assert!((b.bushing_housing_bearing.stress - (3.0 / (0.5 * 0.75) + out.pressure)).abs() < 1e-9);
+        assert_eq!(b.bushing_housing_bearing.allowable, 160.0);
+        let net = 3.0 / (1.0 * 0.75) + out.hoop.housing_sigma;
+        assert!((b.net_tension.stress - net).abs() < 1e-9);
+        let plane = 1.0 - 0.25 * 40f64.to_radians().cos();
+        assert!((b.shear_out.stress - 3.0 / (2.0 * plane * 0.75)).abs() < 1e-9);
+        assert_eq!(out.candidates.iter().filter(|c| c.name.starts_with("lug_")).count(), 2);
+    }
+
+    #[test]
+    fn transverse_load_skips_lug_sections() {
+        let out = solve_with(json!({ "load": 3000.0, "edgeLoadAngleDeg": 90.0 }));
+        let b   = out.bearing.unwrap();
+        assert!(b.edge_load.abs() < 1e-9);
+        assert!((b.net_tension.stress - out.hoop.housing_sigma).abs() < 1e-9);
+        assert!(b.shear_out.margin > 1e6);
+    }
+
+    #[test]
+    fn heavy_pin_load_governs() {
+        let out = solve_with(json!({ "load": 60000.0 }));
+        let pin = out.bearing.as_ref().unwrap().pin_bearing.margin;
+        assert!(pin < 0.0);
+        assert!(out.governing.margin <= pin);
+        assert_ne!(out.governing.name, "housing_hoop");
+    }
+}
@@ -0,0 +1,130 @@
+/// Ranked margin summary: every computed check as a candidate failure mode,
+/// and the governing (minimum-margin) mode across the strength checks.
+use crate::types::*;
+
+fn wall_margin(wall: f64, min: f64) -> f64 {
+    if min > 0.0 { wall / min - 1.0 } else { 999.0 }
+}
+
+struct List(Vec<BushingCandidate>);
+
+impl List {
+    fn push(&mut self, name: &str, margin: f64, location: &str, load_case: &str) {
+        self.add(name, margin, location, load_case, CandidateKind::Strength);
+    }
+
+    fn geometry(&mut self, name: &str, margin: f64, location: &str) {
+        self.add(name, margin, location, "geometry", CandidateKind::Geometry);
+    }
+
+    fn add(&mut self, name: &str, margin: f64, location: &str, load_case: &str, kind: CandidateKind) {
+        self.0.push(BushingCandidate { name: name.into(), margin, location: location.into(), load_case: load_case.into(), kind });
+    }
+}
+
+/// Collect every check from the assembled output, ranked by ascending margin.
+/// Ties keep collection order, so the housing hoop check leads.
+pub fn collect(input: &BushingInput, out: &BushingOutput) -> Vec<BushingCandidate> {
+    let mut c = List(vec![]);
+    let fit   = "interference_fit";
+
+    c.push("housing_hoop", out.hoop.housing_ms, "housing_bore", fit);
+    c.push("bushing_hoop", out.hoop.bushing_ms, "bushing_id", fit);
+    let lig_case = if input.load.is_some() { "fit_plus_pin_load" } else { fit };
+    c.push("ligament", out.hoop.ligament_ms, "edge_ligament", lig_case);
+
+    let ed = &out.edge_distance;
+    c.geometry("edge_distance_sequence", ed.ed_actual / ed.ed_min_sequence - 1.0, "part_edge");
+    // An unreachable requirement is reported as an error warning, not a ratio
+    if let Some(min) = ed.ed_min_strength {
+        c.push("edge_distance_strength", ed.ed_actual / min - 1.0, "part_edge", lig_case);
+    }
+
+    if let Some(b) = &out.bearing {
+        c.push("pin_bearing", b.pin_bearing.margin, "bushing_id", "pin_load");
+        c.push("bushing_housing_bearing", b.bushing_housing_bearing.margin, "bushing_housing_interface", "fit_plus_pin_load");
+        c.push("lug_net_tension", b.net_tension.margin, "lug_net_section", "fit_plus_pin_load");
+        c.push("lug_shear_out", b.shear_out.margin, "shear_out_planes", "pin_load");
+    }
+
+    c.geometry("straight_wall", wall_margin(out.sleeve_wall, input.min_wall_straight), "bushing_wall");
+    if let Some(neck) = out.neck_wall {
+        c.geometry("neck_wall", wall_margin(neck, input.min_wall_neck), "profile_minimum");
+    }
+    if let Some(cs) = &out.cs_solved.id {
+        // Wall left at the internal countersink lip, under any external countersink
+        let outer = out.cs_solved.od.as_ref().map_or(out.od_installed, |e| e.dia.max(out.od_installed));
+        c.geometry("countersink_wall", wall_margin((outer - cs.dia) / 2.0, input.min_wall_neck), "countersink_lip");
+    }
+
+    if let Some(f) = &out.flange {
+        let case = if f.axial_load_source == "entered" { "axial_load" } else { "install_force" };
+        c.push("flange_bearing", f.bearing_ms, "flange_seat", case);
+        c.push("flange_shear", f.shear_ms, "flange_neck", case);
+    }
+
+    for st in out.service_envelope.states.iter().filter(|st| st.id == "hot" || st.id == "cold") {
+        c.push(&format!("service_{}", st.id), st.margin, "housing_bore", &st.id);
+    }
+
+    let mut list = c.0;
+    list.sort_by(|a, b| a.margin.total_cmp(&b.margin));
+    list
+}
+
+/// Minimum-margin strength candidate; `collect` always yields the hoop checks.
+/// Geometric shortfalls are left to their warnings and approval rules.
+pub fn governing(candidates: &[BushingCandidate]) -> BushingCandidate {
+    candidates
+        .iter()
+        .find(|c| c.kind == CandidateKind::Strength)
+        .cloned()
+        .expect("candidate list holds the hoop checks")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_support::solve_with, types::*};
+    use serde_json::json;
+
+    fn names(out: &BushingOutput) -> Vec<&str> {
+        out.candidates.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn ranked_and_governing_is_minimum() {
+        let out = solve_with(json!({ "load": 3000.0, "serviceTemperatureHot": 250.0 }));
+        assert!(out.candidates.windows(2).all(|w| w[0].margin <= w[1].margin));
+        let strength = out.candidates.iter().filter(|c| c.kind == CandidateKind::Strength);
+        let min      = strength.clone().map(|c| c.margin).fold(f64::INFINITY, f64::min);
+        assert_eq!(out.governing.margin, min);
+        assert_eq!(out.governing.name, strength.clone().next().unwrap().name);
+        for n in ["housing_hoop", "ligament", "edge_distance_sequence", "pin_bearing", "straight_wall", "service_hot"] {
+            assert!(names(&out).contains(&n), "missing {n}");
+        }
+        let hot = out.candidates.iter().find(|c| c.name == "service_hot").unwrap();
+        assert_eq!(hot.load_case, "hot");
+    }
+
+    #[test]
+    fn geometry_checks_carry_location() {
+        let out = solve_with(json!({ "idType": "countersink", "csMode": "depth_angle", "csDepth": 0.05, "csAngle": 90.0 }));
+        let cs  = out.candidates.iter().find(|c| c.name == "countersink_wall").expect("cs wall");
+        assert_eq!(cs.location, "countersink_lip");
+        assert!((cs.margin - ((out.od_installed - 0.35) / 2.0 / 0.005 - 1.0)).abs() < 1e-9);
+        let seq = out.candidates.iter().find(|c| c.name == "edge_distance_sequence").unwrap();
+        assert!((seq.margin - (1.0 / 0.75 - 1.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn short_sequence_edge_distance_is_conditional_not_governing() {
+        let out = solve_with(json!({ "edgeDist": 0.74 }));
+        let seq = out.candidates.iter().find(|c| c.name == "edge_distance_sequence").unwrap();
+        assert!(seq.margin < 0.0 && seq.kind == CandidateKind::Geometry);
+        assert_ne!(out.governing.name, "edge_distance_sequence");
+        assert!(out.governing.margin > 0.0);
+        assert_eq!(out.review.decision, ApprovalDecision::Conditional);
+        assert!(!out.review.findings.iter().any(|f| f.rule_id == "common.negative_margin"));
+        assert!(out.review.findings.iter().any(|f| f.rule_id == "common.edge_distance_sequence"));
+    }
+}
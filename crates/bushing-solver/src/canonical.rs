@@ -0,0 +1,161 @@
+/// Canonical export of a normalized input: the camelCase document the
+/// frontend sends, in the system it was entered in, every default filled in,
+/// that normalizes back to the same `BushingInput` and so re-solves to the
+/// same output byte for byte.
+use serde_json::{Map, Value};
+
+use crate::{
+    error::BushingError,
+    inputs::{self, camel_case},
+    migrate::{CURRENT_VERSION, VERSION_KEY},
+    reader::FieldShape,
+    types::*,
+    units::{Convert, Scale},
+};
+
+/// Significant digits kept for values scaled out of internal units; enough to
+/// recover what was typed (12.7 mm, not 12.699999999999999).
+const SCALED_DIGITS: usize = 12;
+
+/// `input` as a current-version document in `input.units`. Keys are the
+/// parser's primary names, absent optional fields are omitted and objects
+/// are key-sorted.
+pub fn export(input: &BushingInput) -> Result<Value, BushingError> {
+    let (fields, _) = inputs::describe();
+    let mut entered = input.clone();
+    entered.convert(&Scale::from_internal(input.units));
+    let scaled  = round_scaled(serde_json::to_value(&entered)?, &serde_json::to_value(input)?);
+    let mut doc = rename(scaled, "", &fields);
+    if let Value::Object(m) = &mut doc {
+        m.insert(VERSION_KEY.into(), Value::from(CURRENT_VERSION));
+    }
+    Ok(doc)
+}
+
+/// [`export`] as compact JSON text.
+pub fn to_string(input: &BushingInput) -> Result<String, BushingError> {
+    Ok(serde_json::to_string(&export(input)?)?)
+}
+
+/// Primary key of the serde field `key` inside the object at `parent`.
+/// Fields the parser does not read one by one (reading lists, inline
+/// materials) take the plain camelCase form, which it maps back.
+fn primary(key: &str, parent: &str, fields: &[FieldShape]) -> String {
+    fields
+        .iter()
+        .find(|f| f.parent == parent && f.keys.iter().any(|k| *k == key || inputs::snake_case(k) == key))
+        .map_or_else(|| camel_case(key), |f| f.keys[0].to_owned())
+}
+
+/// Round the numbers of `v` that differ from their `internal` counterpart,
+/// i.e. those the unit conversion touched.
+fn round_scaled(v: Value, internal: &Value) -> Value {
+    match (v, internal) {
+        (Value::Object(m), Value::Object(i)) => Value::Object(
+            m.into_iter()
+                .map(|(k, x)| {
+                    let x = round_scaled(x, i.get(&k).unwrap_or(&Value::Null));
+                    (k, x)
+                })
+                .collect(),
+        ),
+        (Value::Array(a), Value::Array(i)) => {
+            Value::Array(a.into_iter().zip(i).map(|(x, xi)| round_scaled(x, xi)).collect())
+        }
+        (Value::Number(n), _) if Some(&n) != internal.as_number() => n
+            .as_f64()
+            .and_then(|x| format!("{:.*e}", SCALED_DIGITS - 1, x).parse::<f64>().ok())
+            .map_or(Value::Number(n), Value::from),
+        (v, _) => v,
+    }
+}
+
+fn rename(v: Value, pointer: &str, fields: &[FieldShape]) -> Value {
+    match v {
+        Value::Object(m) => {
+            let renamed: Map<String, Value> = m
+                .into_iter()
+                .filter(|(_, x)| !x.is_null())
+                .map(|(k, x)| {
+                    let key = primary(&k, pointer, fields);
+                    let x   = rename(x, &format!("{pointer}/{key}"), fields);
+                    (key, x)
+                })
+                .collect();
+            Value::Object(renamed)
+        }
+        Value::Array(a) => Value::Array(a.into_iter().map(|x| rename(x, pointer, fields)).collect()),
+        _               => v,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        inputs::BushingInputRaw,
+        solver,
+        test_support::{metric, raw_with},
+    };
+    use serde_json::json;
+
+    fn round_trip(input: &BushingInput) -> BushingInput {
+        let (again, issues) = BushingInputRaw(export(input).unwrap()).normalize_with(InputMode::Strict).unwrap();
+        assert!(issues.is_empty(), "{issues:?}");
+        again
+    }
+
+    #[test]
+    fn export_uses_primary_keys_and_filled_defaults() {
+        let input = raw_with(json!({
+            "bushingType": "countersink", "id_cs": { "def_type": "dia_angle", "dia": 0.4, "angle_deg": 100.0 },
+            "standardsBasis": "faa_ac_43_13", "materialBasis": "b", "dT": 40.0,
+            "measuredPart": { "enabled": true, "bore": { "readings": [0.501, { "dia": 0.502, "angleDeg": 90.0 }] } },
+        }))
+        .normalize()
+        .unwrap();
+        let doc = export(&input).unwrap();
+        assert_eq!(doc["idCS"], json!({ "angleDeg": 100.0, "defType": "dia_angle", "dia": 0.4 }));
+        assert_eq!(doc["dT"], json!(40.0));
+        assert_eq!(doc["friction"], json!(0.15));
+        assert_eq!(doc["minWallStraight"], json!(0.010));
+        assert_eq!(doc["standardsBasis"], json!("faa_ac_43_13"));
+        assert_eq!(doc["measuredPart"]["bore"]["readings"][1], json!({ "angleDeg": 90.0, "dia": 0.502 }));
+        assert_eq!(doc[VERSION_KEY], json!(CURRENT_VERSION));
+        assert!(doc.get("load").is_none() && doc.get("bore_dia").is_none());
+        let text = to_string(&round_trip(&input)).unwrap();
+        assert_eq!(text, to_string(&input).unwrap());
+    }
+
+    #[test]
+    fn metric_and_inline_materials_reproduce_the_same_output() {
+        let input = raw_with(metric(json!({
+            "minWallNeck": 0.2, "dT": -20.0,
+            "matHousing": {
+                "eKsi": 10300.0, "syKsi": 70.0, "fbruKsi": 148.0, "fsuKsi": 48.0, "nu": 0.33,
+                "alphaUF": 12.9, "densityLbIn3": 0.101, "cpBtuLbF": 0.23, "eCurve": [[70.0, 1.0], [300.0, 0.95]],
+            },
+        })))
+        .normalize()
+        .unwrap();
+        let doc = export(&input).unwrap();
+        assert_eq!(doc["units"], json!("metric"));
+        assert!(doc.get("outputUnits").is_none());
+        assert_eq!((doc["boreDia"].as_f64(), doc["interference"].as_f64()), (Some(12.7), Some(0.0381)));
+        assert_eq!((doc["dT"].as_f64(), doc["minWallNeck"].as_f64()), (Some(-20.0), Some(0.2)));
+        assert_eq!(doc["customMaterials"][0]["eKsi"], json!(10300.0));
+        let again = round_trip(&input);
+        let solved = |i: &BushingInput| serde_json::to_string(&solver::compute(i).unwrap()).unwrap();
+        assert_eq!(solved(&again), solved(&input));
+        assert!(solved(&input).contains("\"length\":\"mm\""));
+    }
+
+    #[test]
+    fn output_echoes_the_submitted_input_once() {
+        let document = || raw_with(json!({ "measuredPart": { "enabled": true, "bore": { "readings": [0.501] } } }));
+        let echo = crate::compute_bushing(document()).unwrap().effective_input.unwrap();
+        assert_eq!(echo["boreDia"], json!(0.5));
+        assert_eq!(Some(echo), crate::canonical_input(document()).ok());
+        assert!(solver::compute(&raw_with(json!({})).normalize().unwrap()).unwrap().effective_input.is_none());
+    }
+}
@@ -0,0 +1,208 @@
+/// Internal / external countersink resolution, depth and diameter bands, and the
+/// minimum radial wall along the bushing profile.
+/// Ported from solveEngine.ts (countersink section) and shared/bushingProfileGeometry.ts.
+use crate::{
+    flange,
+    math::{self, EPS},
+    types::*,
+};
+
+#[derive(Debug, Clone)]
+pub struct SolvedCountersink {
+    pub mode: CsMode,
+    pub dim: CsSolvedDim,
+    pub dia_tol: ToleranceRange,
+    pub depth_tol: ToleranceRange,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Countersinks {
+    pub internal: Option<SolvedCountersink>,
+    pub external: Option<SolvedCountersink>,
+}
+
+pub(crate) fn internal_enabled(input: &BushingInput) -> bool {
+    input.id_cs.as_ref().and_then(|c| c.enabled).unwrap_or(input.id_type == IdType::Countersink)
+}
+
+pub(crate) fn external_enabled(input: &BushingInput) -> bool {
+    input.od_cs.as_ref().and_then(|c| c.enabled).unwrap_or(input.bushing_type == BushingType::Countersink)
+}
+
+fn depth_band(depth: f64, plus: Option<f64>, minus: Option<f64>) -> ToleranceRange {
+    let lower = (depth - minus.unwrap_or(0.0).max(0.0)).max(0.0);
+    let upper = depth + plus.unwrap_or(0.0).max(0.0);
+    math::make_range_at(ToleranceMode::NominalTol, lower, upper, depth)
+}
+
+fn solve_one(mode: CsMode, dia: f64, depth: f64, angle: f64, depth_tol: ToleranceRange, base: &ToleranceRange) -> SolvedCountersink {
+    let dim = math::solve_countersink(mode, dia, depth, angle, base.nominal);
+    SolvedCountersink {
+        mode,
+        dia_tol: math::cs_dia_tolerance(mode, &dim, base, &depth_tol),
+        depth_tol: math::cs_depth_tolerance(mode, &dim, base, &depth_tol),
+        dim,
+    }
+}
+
+/// Resolve the enabled countersinks. The internal one is cut from the bushing
+/// ID, the external one from the bushing OD band `od`.
+pub fn solve(input: &BushingInput, od: &ToleranceRange) -> Countersinks {
+    let id_base = math::make_range_at(ToleranceMode::NominalTol, input.id_bushing, input.id_bushing, input.id_bushing);
+    Countersinks {
+        internal: internal_enabled(input).then(|| {
+            let tol = depth_band(input.cs_depth, input.cs_depth_tol_plus, input.cs_depth_tol_minus);
+            solve_one(input.cs_mode, input.cs_dia, input.cs_depth, input.cs_angle, tol, &id_base)
+        }),
+        external: external_enabled(input).then(|| {
+            let tol = depth_band(input.ext_cs_depth, input.ext_cs_depth_tol_plus, input.ext_cs_depth_tol_minus);
+            solve_one(input.ext_cs_mode, input.ext_cs_dia, input.ext_cs_depth, input.ext_cs_angle, tol, od)
+        }),
+    }
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t.clamp(0.0, 1.0)
+}
+
+/// Minimum radial wall along the profile. `z` runs from the housing top face
+/// (0) into the bore (`housing_len`); a flange sits above at negative `z`.
+pub fn minimum_wall(input: &BushingInput, od_bushing: f64, cs: &Countersinks) -> f64 {
+    let len     = input.housing_len.max(EPS);
+    let r_outer = od_bushing / 2.0;
+    let r_inner = input.id_bushing / 2.0;
+    let flanged = input.bushing_type == BushingType::Flanged;
+    let flange_t = if flanged { input.flange_thk.unwrap_or(0.0).max(0.0) } else { 0.0 };
+    let flange_r = flange::flange_od(input).map_or(r_outer, |d| (d / 2.0).max(r_outer));
+    let top     = -flange_t;
+
+    let (ext_top, z_ext) = cs.external.as_ref().map_or((r_outer, 0.0), |c| {
+        ((c.dim.dia / 2.0).max(r_outer), c.dim.depth.clamp(0.0, len))
+    });
+    let (int_top, z_int) = cs.internal.as_ref().map_or((r_inner, top), |c| {
+        ((c.dim.dia / 2.0).max(r_inner), (top + c.dim.depth).clamp(top, len))
+    });
+
+    let outer = |z: f64| {
+        if flanged && z < 0.0 {
+            flange_r
+        } else if z_ext > 0.0 && z <= z_ext {
+            lerp(ext_top, r_outer, z / z_ext)
+        } else {
+            r_outer
+        }
+    };
+    let inner = |z: f64| {
+        if z_int > top && z <= z_int {
+            lerp(int_top, r_inner, (z - top) / (z_int - top))
+        } else {
+            r_inner
+        }
+    };
+
+    let eps = len.max(1.0) * 1e-6;
+    [top, 0.0, z_ext, z_int, len]
+        .into_iter()
+        .flat_map(|z| [z - eps, z, z + eps])
+        .filter(|z| *z >= top && *z <= len)
+        .map(|z| outer(z) - inner(z))
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn angle_valid(angle: f64) -> bool {
+    angle.is_finite() && angle > 0.0 && angle < 180.0
+}
+
+/// Countersink warnings derived from the solved output.
+pub(crate) fn warnings(input: &BushingInput, out: &BushingOutput) -> Vec<BushingWarning> {
+    let mut w = vec![];
+    let mut push = |code, message: &str| w.push(BushingWarning { code, message: message.into(), severity: WarningSeverity::Warning });
+
+    if let Some(cs) = &out.cs_solved.id {
+        let angle = if input.cs_mode == CsMode::DiaDepth { cs.angle_deg } else { input.cs_angle };
+        if cs.dia + EPS < input.id_bushing {
+            push(BushingWarningCode::InternalCsDiaLtId, "Internal countersink diameter should be >= bushing ID.");
+        }
+        if !angle_valid(angle) {
+            push(BushingWarningCode::InternalCsAngleInvalid, "Internal countersink angle must be between 0 and 180 degrees.");
+        }
+        let cuts_through = out.neck_wall.is_some_and(|n| n <= 0.0);
+        if !cs.dia.is_finite() || !cs.depth.is_finite() || cs.depth > input.housing_len + input.flange_thk.unwrap_or(0.0) || cuts_through {
+            push(BushingWarningCode::InternalCsGeometryInvalid, "Internal countersink geometry is invalid for the selected mode.");
+        }
+    }
+    if let Some(cs) = &out.cs_solved.od {
+        let angle = if input.ext_cs_mode == CsMode::DiaDepth { cs.angle_deg } else { input.ext_cs_angle };
+        if cs.dia + EPS < out.od_installed {
+            push(BushingWarningCode::ExternalCsDiaLtOd, "External countersink diameter should be >= installed OD baseline.");
+        }
+        if !angle_valid(angle) {
+            push(BushingWarningCode::ExternalCsAngleInvalid, "External countersink angle must be between 0 and 180 degrees.");
+        }
+        if !cs.dia.is_finite() || !cs.depth.is_finite() || cs.depth > input.housing_len || cs.dia >= input.housing_width {
+            push(BushingWarningCode::ExternalCsGeometryInvalid, "External countersink geometry is invalid for the selected mode.");
+        }
+    }
+    w
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_support::solve_with, types::*};
+    use serde_json::json;
+
+    fn has(out: &BushingOutput, code: BushingWarningCode) -> bool {
+        out.warning_codes.iter().any(|w| w.code == code)
+    }
+
+    #[test]
+    fn internal_depth_angle_solves_dia_and_bands() {
+        let out = solve_with(json!({
+            "idType": "countersink", "csMode": "depth_angle", "csDepth": 0.05, "csAngle": 90.0,
+            "csDepthTolPlus": 0.01, "csDepthTolMinus": 0.01,
+        }));
+        let cs = out.cs_solved.id.as_ref().expect("internal cs");
+        assert!((cs.dia - (0.25 + 2.0 * 0.05)).abs() < 1e-9);
+        let dia = out.tolerance.cs_internal_dia.as_ref().unwrap();
+        assert!((dia.lower - 0.33).abs() < 1e-9 && (dia.upper - 0.37).abs() < 1e-9);
+        let depth = out.tolerance.cs_internal_depth.as_ref().unwrap();
+        assert!((depth.lower - 0.04).abs() < 1e-12 && (depth.upper - 0.06).abs() < 1e-12);
+        // Remaining wall at the countersink lip
+        let neck = out.neck_wall.unwrap();
+        assert!((neck - (out.od_installed - 0.35) / 2.0).abs() < 1e-6, "neck {neck}");
+        assert!(out.tolerance.cs_external_dia.is_none());
+    }
+
+    #[test]
+    fn external_dia_angle_solves_depth() {
+        let out = solve_with(json!({
+            "bushingType": "countersink", "extCsMode": "dia_angle", "extCsDia": 0.7, "extCsAngle": 100.0,
+        }));
+        let cs = out.cs_solved.od.as_ref().expect("external cs");
+        let expected = (0.7 - out.od_installed) / 2.0 / 50f64.to_radians().tan();
+        assert!((cs.depth - expected).abs() < 1e-9);
+        assert!(!has(&out, BushingWarningCode::ExternalCsDiaLtOd));
+    }
+
+    #[test]
+    fn nested_cs_object_and_dia_depth_angle() {
+        let out = solve_with(json!({ "idCS": { "enabled": true, "defType": "dia_depth", "dia": 0.35, "depth": 0.05 } }));
+        let cs = out.cs_solved.id.unwrap();
+        assert!((cs.angle_deg - 90.0).abs() < 1e-9, "angle {}", cs.angle_deg);
+    }
+
+    #[test]
+    fn invalid_countersinks_are_flagged() {
+        let out = solve_with(json!({
+            "idType": "countersink", "csMode": "dia_depth", "csDia": 0.2, "csDepth": 0.05,
+            "bushingType": "countersink", "extCsMode": "dia_angle", "extCsDia": 0.45, "extCsAngle": 200.0,
+        }));
+        assert!(has(&out, BushingWarningCode::InternalCsDiaLtId));
+        assert!(has(&out, BushingWarningCode::InternalCsAngleInvalid));
+        assert!(has(&out, BushingWarningCode::ExternalCsDiaLtOd));
+        assert!(has(&out, BushingWarningCode::ExternalCsAngleInvalid));
+
+        let through = solve_with(json!({ "idType": "countersink", "csMode": "dia_angle", "csDia": 0.6 }));
+        assert!(has(&through, BushingWarningCode::InternalCsGeometryInvalid));
+    }
+}
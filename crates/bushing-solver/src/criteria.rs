@@ -0,0 +1,108 @@
+/// Equivalent-stress failure criteria and allowable basis, evaluated across the
+/// sampled Lamé field of each region.
+use crate::{math, types::*};
+
+pub const DEFAULT_FITTING_FACTOR: f64 = 1.15;
+
+const ALL: [FailureCriterion; 3] = [FailureCriterion::MaxPrincipal, FailureCriterion::Tresca, FailureCriterion::VonMises];
+
+/// Equivalent stress of a principal stress triple under `criterion`.
+pub fn equivalent(criterion: FailureCriterion, s1: f64, s2: f64, s3: f64) -> f64 {
+    match criterion {
+        FailureCriterion::MaxPrincipal => s1.abs().max(s2.abs()).max(s3.abs()),
+        FailureCriterion::Tresca       => (s1 - s2).abs().max((s2 - s3).abs()).max((s3 - s1).abs()),
+        FailureCriterion::VonMises     => math::von_mises(s1, s2, s3),
+    }
+}
+
+pub fn fitting_factor(input: &BushingInput) -> f64 {
+    input.fitting_factor.unwrap_or(DEFAULT_FITTING_FACTOR)
+}
+
+/// Allowable stress (ksi) for a material on the selected basis. Ultimate falls
+/// back to yield for materials without a published Ftu.
+pub fn allowable(basis: AllowableBasis, mat: &MaterialProps, fitting_factor: f64) -> f64 {
+    match basis {
+        AllowableBasis::Yield    => mat.sy_ksi,
+        AllowableBasis::Ultimate => mat.ftu_ksi.unwrap_or(mat.sy_ksi) / fitting_factor.max(1.0),
+        AllowableBasis::Bearing  => mat.fbru_ksi,
+    }
+}
+
+pub(crate) fn margin(allowable: f64, stress: f64) -> f64 {
+    if stress > 0.0 { allowable / stress - 1.0 } else { 999.0 }
+}
+
+/// Peak of each criterion over the region's samples and where it occurs.
+pub fn evaluate_region(samples: &[StressSample], allowable: f64) -> RegionCriteria {
+    let margins = ALL
+        .iter()
+        .map(|&criterion| {
+            let (max_stress, at_radius) = samples
+                .iter()
+                .map(|s| (equivalent(criterion, s.sigma_r, s.sigma_theta, s.sigma_axial), s.r))
+                .fold((0.0, samples.first().map_or(0.0, |s| s.r)), |best, cur| if cur.0 > best.0 { cur } else { best });
+            CriterionMargin { criterion, max_stress, at_radius, margin: margin(allowable, max_stress) }
+        })
+        .collect();
+    RegionCriteria { allowable, margins }
+}
+
+pub fn build_criteria(input: &BushingInput, mat_h: &MaterialProps, mat_b: &MaterialProps, field: &LameField) -> CriteriaResult {
+    let ff = fitting_factor(input);
+    CriteriaResult {
+        selected: input.failure_criterion,
+        allowable_basis: input.allowable_basis,
+        fitting_factor: ff,
+        housing: evaluate_region(&field.housing.samples, allowable(input.allowable_basis, mat_h, ff)),
+        bushing: evaluate_region(&field.bushing.samples, allowable(input.allowable_basis, mat_b, ff)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::solve_with;
+    use serde_json::json;
+
+    fn pick(r: &RegionCriteria, c: FailureCriterion) -> &CriterionMargin {
+        r.margins.iter().find(|m| m.criterion == c).unwrap()
+    }
+
+    #[test]
+    fn criteria_order_on_the_bushing_bore() {
+        let out = solve_with(json!({}));
+        let b   = &out.criteria.bushing;
+        let mp  = pick(b, FailureCriterion::MaxPrincipal);
+        let tr  = pick(b, FailureCriterion::Tresca);
+        let vm  = pick(b, FailureCriterion::VonMises);
+        // Bushing ID is uniaxial hoop compression under plane stress: all three agree there
+        assert!((mp.max_stress - tr.max_stress).abs() < 1e-9);
+        assert!((vm.max_stress - mp.max_stress).abs() < 1e-9);
+        assert!((vm.at_radius - 0.125).abs() < 1e-12);
+        assert!((out.hoop.bushing_ms - vm.margin).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tresca_is_most_conservative_in_the_housing() {
+        let out = solve_with(json!({ "failureCriterion": "tresca" }));
+        let h   = &out.criteria.housing;
+        let tr  = pick(h, FailureCriterion::Tresca);
+        assert!(tr.max_stress >= pick(h, FailureCriterion::VonMises).max_stress);
+        assert!(tr.max_stress > pick(h, FailureCriterion::MaxPrincipal).max_stress);
+        assert!((tr.at_radius - 0.25).abs() < 1e-12);
+        assert!((out.hoop.housing_ms - tr.margin).abs() < 1e-9);
+        assert_eq!(out.criteria.selected, FailureCriterion::Tresca);
+    }
+
+    #[test]
+    fn allowable_basis_selects_material_property() {
+        let ult = solve_with(json!({ "allowableBasis": "ultimate", "fittingFactor": 1.25 }));
+        assert!((ult.criteria.housing.allowable - 83.0 / 1.25).abs() < 1e-9);
+        assert!((ult.criteria.bushing.allowable - 190.0 / 1.25).abs() < 1e-9);
+        let brg = solve_with(json!({ "allowableBasis": "bearing" }));
+        assert_eq!(brg.criteria.housing.allowable, 160.0);
+        assert!(brg.hoop.housing_ms > ult.hoop.housing_ms);
+        assert_eq!(solve_with(json!({})).criteria.fitting_factor, DEFAULT_FITTING_FACTOR);
+    }
+}
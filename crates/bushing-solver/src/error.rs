@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::types::{ValidationIssue, WarningSeverity};
+
 #[derive(Debug, Error)]
 pub enum BushingError {
     #[error("missing required field: {0}")]
@@ -8,8 +10,9 @@ pub enum BushingError {
     #[error("invalid input: {0}")]
     InvalidInput(String),
 
-    #[error("schema validation failed: {0}")]
-    Schema(String),
+    /// Every finding of [`crate::schema::check`], at least one of them an error.
+    #[error("schema validation failed: {}", summarize(.0))]
+    Schema(Vec<ValidationIssue>),
 
     #[error("computation error: {0}")]
     Computation(String),
@@ -17,3 +20,22 @@ pub enum BushingError {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 }
+
+impl BushingError {
+    /// Validation findings carried by a [`BushingError::Schema`]; empty otherwise.
+    pub fn issues(&self) -> &[ValidationIssue] {
+        match self {
+            BushingError::Schema(issues) => issues,
+            _                            => &[],
+        }
+    }
+}
+
+fn summarize(issues: &[ValidationIssue]) -> String {
+    issues
+        .iter()
+        .filter(|i| i.severity == WarningSeverity::Error)
+        .map(|i| format!("{}: {}", i.pointer, i.message))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -0,0 +1,344 @@
+/// Worst-case and statistical (RSS / Monte Carlo) evaluation of the fit across
+/// its tolerance band. Each point reuses [`solver::solve_point`] with the
+/// materials derated to that point's temperature.
+use crate::{
+    materials,
+    service::REFERENCE_TEMP_F,
+    solver::{self, FitPoint},
+    types::*,
+};
+
+/// Default Monte Carlo sample count when `monte_carlo_samples` is omitted.
+pub const DEFAULT_SAMPLES: u32 = 2_000;
+/// Largest Monte Carlo sample count accepted; larger requests are clamped here.
+pub const MAX_SAMPLES: u32 = 1_000_000;
+/// Default Monte Carlo seed when `monte_carlo_seed` is omitted.
+pub const DEFAULT_SEED: u64 = 1;
+/// Percentiles reported from the Monte Carlo run.
+const PERCENTILES: [f64; 5] = [1.0, 5.0, 50.0, 95.0, 99.0];
+/// Tolerance bands are treated as ±3σ about their midpoint.
+const SIGMA_PER_BAND: f64 = 6.0;
+
+/// Evaluate the fit band for the requested [`BushingAnalysisMode`] from the
+/// room-temperature materials. Returns `None` for nominal-only analysis.
+pub fn build_fit_band(
+    input: &BushingInput,
+    tolerance: &ToleranceResult,
+    mat_h: &MaterialProps,
+    mat_b: &MaterialProps,
+) -> Option<FitBandResult> {
+    if input.analysis_mode == BushingAnalysisMode::Nominal {
+        return None;
+    }
+    let bore = &tolerance.bore;
+    let od   = &tolerance.od_bushing;
+
+    let corners = vec![
+        corner(input, mat_h, mat_b, "min_interference", "Min interference (max bore / min OD)", bore.upper, od.lower, input.d_t),
+        corner(input, mat_h, mat_b, "nominal", "Nominal", bore.nominal, od.nominal, input.d_t),
+        corner(input, mat_h, mat_b, "max_interference", "Max interference (min bore / max OD)", bore.lower, od.upper, input.d_t),
+    ];
+    let governing = corners
+        .iter()
+        .min_by(|a, b| a.min_margin.total_cmp(&b.min_margin))
+        .expect("corner list is non-empty");
+    let governing_corner_id = governing.id.clone();
+    let min_margin = governing.min_margin;
+
+    let (rss, monte_carlo) = if input.analysis_mode == BushingAnalysisMode::Statistical {
+        (Some(build_rss(input, tolerance, mat_h, mat_b)), Some(build_monte_carlo(input, tolerance, mat_h, mat_b)))
+    } else {
+        (None, None)
+    };
+
+    Some(FitBandResult {
+        mode: input.analysis_mode,
+        corners,
+        governing_corner_id,
+        min_margin,
+        rss,
+        monte_carlo,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn corner(
+    input: &BushingInput,
+    mat_h: &MaterialProps,
+    mat_b: &MaterialProps,
+    id: &str,
+    label: &str,
+    bore_dia: f64,
+    od_bushing: f64,
+    d_t: f64,
+) -> FitCornerResult {
+    let interference = od_bushing - bore_dia;
+    let FitPoint { hoop, physics, .. } = solve_at(input, mat_h, mat_b, bore_dia, interference, d_t);
+    FitCornerResult {
+        id: id.into(),
+        label: label.into(),
+        bore_dia,
+        od_bushing,
+        interference,
+        d_t,
+        min_margin: hoop.housing_ms.min(hoop.bushing_ms),
+        hoop,
+        physics,
+    }
+}
+
+/// [`solver::solve_point`] with moduli, allowables and CTE taken at `70 + d_t`.
+fn solve_at(input: &BushingInput, mat_h: &MaterialProps, mat_b: &MaterialProps, bore_dia: f64, interference: f64, d_t: f64) -> FitPoint {
+    let temp_f = REFERENCE_TEMP_F + d_t;
+    let mat_h  = materials::at_temperature(mat_h, temp_f);
+    let mat_b  = materials::at_temperature(mat_b, temp_f);
+    solver::solve_point(input, &mat_h, &mat_b, bore_dia, interference, d_t)
+}
+
+fn band_mid(r: &ToleranceRange) -> f64 {
+    (r.lower + r.upper) / 2.0
+}
+
+fn band_sigma(r: &ToleranceRange) -> f64 {
+    (r.upper - r.lower).max(0.0) / SIGMA_PER_BAND
+}
+
+/// Root-sum-square ±3σ interference limits. Thermal scatter is folded into the
+/// interference sigma through the material CTE mismatch.
+fn build_rss(
+    input: &BushingInput,
+    tolerance: &ToleranceResult,
+    mat_h: &MaterialProps,
+    mat_b: &MaterialProps,
+) -> RssResult {
+    let bore_mid = band_mid(&tolerance.bore);
+    let od_mid   = band_mid(&tolerance.od_bushing);
+    let temp_f   = REFERENCE_TEMP_F + input.d_t;
+    let alpha_h  = materials::at_temperature(mat_h, temp_f).alpha_u_f;
+    let alpha_b  = materials::at_temperature(mat_b, temp_f).alpha_u_f;
+    let sigma_thermal = input.d_t_std_dev.unwrap_or(0.0).abs()
+        * (alpha_h - alpha_b).abs()
+        * bore_mid
+        * 1e-6;
+    let sigma = (band_sigma(&tolerance.bore).powi(2)
+        + band_sigma(&tolerance.od_bushing).powi(2)
+        + sigma_thermal.powi(2))
+    .sqrt();
+    let mean = od_mid - bore_mid;
+
+    let low  = corner(input, mat_h, mat_b, "rss_low", "RSS -3σ interference", bore_mid, bore_mid + mean - 3.0 * sigma, input.d_t);
+    let high = corner(input, mat_h, mat_b, "rss_high", "RSS +3σ interference", bore_mid, bore_mid + mean + 3.0 * sigma, input.d_t);
+    RssResult { interference_mean: mean, interference_sigma: sigma, low, high }
+}
+
+/// Seeded Monte Carlo over normally distributed bore, OD and `d_t`.
+fn build_monte_carlo(
+    input: &BushingInput,
+    tolerance: &ToleranceResult,
+    mat_h: &MaterialProps,
+    mat_b: &MaterialProps,
+) -> MonteCarloResult {
+    let samples = sample_count(input);
+    let seed    = input.monte_carlo_seed.unwrap_or(DEFAULT_SEED);
+    let mut rng = SplitMix64::new(seed);
+
+    let (bore_mu, bore_sd) = (band_mid(&tolerance.bore), band_sigma(&tolerance.bore));
+    let (od_mu, od_sd)     = (band_mid(&tolerance.od_bushing), band_sigma(&tolerance.od_bushing));
+    let dt_sd = input.d_t_std_dev.unwrap_or(0.0).abs();
+
+    let mut interference = Vec::with_capacity(samples as usize);
+    let mut pressure     = Vec::with_capacity(samples as usize);
+    let mut ms_h         = Vec::with_capacity(samples as usize);
+    let mut ms_b         = Vec::with_capacity(samples as usize);
+    let mut ms_min       = Vec::with_capacity(samples as usize);
+    let mut clearance    = 0u32;
+    let mut negative     = 0u32;
+
+    for _ in 0..samples {
+        let bore = bore_mu + bore_sd * rng.next_normal();
+        let od   = od_mu + od_sd * rng.next_normal();
+        let d_t  = input.d_t + dt_sd * rng.next_normal();
+        let p    = solve_at(input, mat_h, mat_b, bore, od - bore, d_t);
+        let m    = p.hoop.housing_ms.min(p.hoop.bushing_ms);
+        if p.delta_total <= 0.0 {
+            clearance += 1;
+        }
+        if m < 0.0 {
+            negative += 1;
+        }
+        interference.push(od - bore);
+        pressure.push(p.pressure);
+        ms_h.push(p.hoop.housing_ms);
+        ms_b.push(p.hoop.bushing_ms);
+        ms_min.push(m);
+    }
+    for v in [&mut interference, &mut pressure, &mut ms_h, &mut ms_b, &mut ms_min] {
+        v.sort_by(f64::total_cmp);
+    }
+
+    let percentiles = PERCENTILES
+        .iter()
+        .map(|&pct| FitPercentile {
+            percentile: pct,
+            interference: percentile(&interference, pct),
+            contact_pressure: percentile(&pressure, pct),
+            margin_housing: percentile(&ms_h, pct),
+            margin_bushing: percentile(&ms_b, pct),
+            min_margin: percentile(&ms_min, pct),
+        })
+        .collect();
+
+    MonteCarloResult {
+        samples,
+        seed,
+        clearance_fraction: clearance as f64 / samples as f64,
+        negative_margin_fraction: negative as f64 / samples as f64,
+        percentiles,
+    }
+}
+
+/// Requested sample count, defaulted and clamped to `1..=MAX_SAMPLES`.
+fn sample_count(input: &BushingInput) -> u32 {
+    input.monte_carlo_samples.unwrap_or(DEFAULT_SAMPLES).clamp(1, MAX_SAMPLES)
+}
+
+/// Nearest-rank percentile of an ascending-sorted slice.
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Small deterministic PRNG (SplitMix64) so results are reproducible per seed
+/// without pulling in an RNG crate.
+struct SplitMix64 {
+    state: u64,
+    spare: Option<f64>,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed, spare: None }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in (0, 1].
+    fn next_unit(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 1.0) / (1u64 << 53) as f64
+    }
+
+    /// Standard normal via Box–Muller.
+    fn next_normal(&mut self) -> f64 {
+        if let Some(z) = self.spare.take() {
+            return z;
+        }
+        let r     = (-2.0 * self.next_unit().ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * self.next_unit();
+        self.spare = Some(r * theta.sin());
+        r * theta.cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{materials, test_support::input_with, tolerance};
+    use serde_json::json;
+
+    fn band(mode: &str) -> FitBandResult {
+        let input = input_with(json!({
+            "edgeDist": 0.75, "boreTolPlus": 0.0002, "boreTolMinus": 0.0002,
+            "interferenceTolPlus": 0.0004, "interferenceTolMinus": 0.0004,
+            "analysisMode": mode, "monteCarloSamples": 500, "monteCarloSeed": 7,
+        }));
+        let tol   = tolerance::build_tolerance(&input);
+        let mat_h = materials::lookup(&input.mat_housing).unwrap();
+        let mat_b = materials::lookup(&input.mat_bushing).unwrap();
+        build_fit_band(&input, &tol, &mat_h, &mat_b).expect("band requested")
+    }
+
+    #[test]
+    fn worst_case_corners_bracket_nominal() {
+        let b = band("worst_case");
+        assert!(b.rss.is_none() && b.monte_carlo.is_none());
+        let p: Vec<f64> = b.corners.iter().map(|c| c.physics.contact_pressure).collect();
+        assert!(p[0] < p[1] && p[1] < p[2], "pressures {p:?}");
+        assert_eq!(b.governing_corner_id, "max_interference");
+    }
+
+    #[test]
+    fn monte_carlo_is_reproducible_per_seed() {
+        let a = band("statistical").monte_carlo.unwrap();
+        let b = band("statistical").monte_carlo.unwrap();
+        assert_eq!(a.samples, 500);
+        assert_eq!(a.percentiles[2].min_margin, b.percentiles[2].min_margin);
+        assert!(a.percentiles[0].contact_pressure <= a.percentiles[4].contact_pressure);
+    }
+
+    #[test]
+    fn monte_carlo_derates_materials_at_each_sampled_temperature() {
+        let input = input_with(json!({
+            "edgeDist": 0.75, "analysisMode": "statistical", "monteCarloSamples": 1, "monteCarloSeed": 7,
+            "dT": 100.0, "dTStdDev": 80.0,
+        }));
+        let tol       = tolerance::build_tolerance(&input);
+        let mut mat_h = materials::lookup(&input.mat_housing).unwrap();
+        mat_h.e_curve = vec![[70.0, 1.0], [370.0, 0.5]];
+        let mat_b     = materials::lookup(&input.mat_bushing).unwrap();
+        let mc        = build_fit_band(&input, &tol, &mat_h, &mat_b).unwrap().monte_carlo.unwrap();
+
+        let mut rng = SplitMix64::new(7);
+        let bore    = band_mid(&tol.bore) + band_sigma(&tol.bore) * rng.next_normal();
+        let od      = band_mid(&tol.od_bushing) + band_sigma(&tol.od_bushing) * rng.next_normal();
+        let d_t     = input.d_t + 80.0 * rng.next_normal();
+        let at      = |t: f64| materials::at_temperature(&mat_h, REFERENCE_TEMP_F + t);
+        let sampled = solver::solve_point(&input, &at(d_t), &mat_b, bore, od - bore, d_t);
+        let frozen  = solver::solve_point(&input, &at(input.d_t), &mat_b, bore, od - bore, d_t);
+        assert_eq!(mc.percentiles[2].contact_pressure, sampled.pressure);
+        assert_ne!(sampled.pressure, frozen.pressure);
+    }
+
+    #[test]
+    fn sample_count_is_clamped_to_the_maximum() {
+        let with = |n: u64| sample_count(&input_with(json!({ "monteCarloSamples": n })));
+        assert_eq!(with(0), 1);
+        assert_eq!(with(u64::from(u32::MAX)), MAX_SAMPLES);
+        assert_eq!(sample_count(&input_with(json!({}))), DEFAULT_SAMPLES);
+    }
+
+    #[test]
+    fn monte_carlo_interference_is_before_thermal_like_the_corners() {
+        let input = input_with(json!({
+            "edgeDist": 0.75, "analysisMode": "statistical", "monteCarloSamples": 1, "monteCarloSeed": 7,
+            "dT": 150.0,
+        }));
+        let tol   = tolerance::build_tolerance(&input);
+        let mat_h = materials::lookup(&input.mat_housing).unwrap();
+        let mat_b = materials::lookup(&input.mat_bushing).unwrap();
+        let mc    = build_fit_band(&input, &tol, &mat_h, &mat_b).unwrap().monte_carlo.unwrap();
+
+        let mut rng = SplitMix64::new(7);
+        let bore    = band_mid(&tol.bore) + band_sigma(&tol.bore) * rng.next_normal();
+        let od      = band_mid(&tol.od_bushing) + band_sigma(&tol.od_bushing) * rng.next_normal();
+        let p       = solve_at(&input, &mat_h, &mat_b, bore, od - bore, input.d_t);
+        assert_eq!(mc.percentiles[2].interference, od - bore);
+        assert_ne!(p.delta_total, od - bore);
+    }
+
+    #[test]
+    fn rss_limits_sit_inside_worst_case() {
+        let b   = band("statistical");
+        let rss = b.rss.unwrap();
+        assert!(rss.low.interference >= b.corners[0].interference - 1e-12);
+        assert!(rss.high.interference <= b.corners[2].interference + 1e-12);
+    }
+}
@@ -0,0 +1,124 @@
+/// Flanged bushing checks: neck wall, flange bearing on the housing face and
+/// flange shear-off at the neck.
+use crate::types::*;
+
+/// Resolved flange OD (`flange_od`, falling back to the legacy `flange_dia`).
+pub(crate) fn flange_od(input: &BushingInput) -> Option<f64> {
+    input.flange_od.or(input.flange_dia)
+}
+
+fn margin(allowable: f64, stress: f64) -> f64 {
+    if stress > 0.0 { allowable / stress - 1.0 } else { 999.0 }
+}
+
+/// Evaluate the flange for a flanged bushing. The axial load defaults to the
+/// install force, which the flange carries when the bushing is pressed by it.
+/// `bore_dia` is the resolved bore nominal and `neck_wall` the minimum
+/// profile wall under the flange.
+pub fn build_flange(
+    input: &BushingInput,
+    mat_h: &MaterialProps,
+    mat_b: &MaterialProps,
+    bore_dia: f64,
+    od_bushing: f64,
+    neck_wall: f64,
+    install_force: f64,
+) -> Option<FlangeResult> {
+    if input.bushing_type != BushingType::Flanged {
+        return None;
+    }
+    let flange_od  = flange_od(input)?;
+    let flange_thk = input.flange_thk?;
+
+    let (axial_load, axial_load_source) = match input.axial_load {
+        Some(p) => (p.abs(), "entered"),
+        None    => (install_force.abs(), "install_force"),
+    };
+    let kips = axial_load / 1000.0;
+
+    // Annular seat between the flange OD and the bore edge
+    let bearing_area      = std::f64::consts::PI / 4.0 * (flange_od.powi(2) - bore_dia.powi(2)).max(0.0);
+    let bearing_stress    = if bearing_area > 0.0 { kips / bearing_area } else { 0.0 };
+    let bearing_allowable = mat_b.fbru_ksi.min(mat_h.fbru_ksi);
+
+    // Cylindrical shear plane through the flange at the neck OD
+    let shear_area      = std::f64::consts::PI * od_bushing * flange_thk;
+    let shear_stress    = if shear_area > 0.0 { kips / shear_area } else { 0.0 };
+    let shear_allowable = mat_b.fsu_ksi;
+
+    Some(FlangeResult {
+        flange_od,
+        flange_thk,
+        neck_wall,
+        axial_load,
+        axial_load_source: axial_load_source.into(),
+        bearing_area,
+        bearing_stress,
+        bearing_allowable,
+        bearing_ms: margin(bearing_allowable, bearing_stress),
+        shear_area,
+        shear_stress,
+        shear_allowable,
+        shear_ms: margin(shear_allowable, shear_stress),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_support::{overlay, solve_with},
+        types::*,
+    };
+    use serde_json::json;
+
+    fn solve(extra: serde_json::Value) -> BushingOutput {
+        solve_with(overlay(json!({ "bushingType": "flanged", "flangeOd": 0.75, "flangeThk": 0.0625 }), extra))
+    }
+
+    #[test]
+    fn flange_bearing_and_shear_from_axial_load() {
+        let out = solve(json!({ "axialLoad": 2000.0 }));
+        let f   = out.flange.as_ref().expect("flanged");
+        let bearing_area = std::f64::consts::PI / 4.0 * (0.75f64.powi(2) - 0.25);
+        assert!((f.bearing_stress - 2.0 / bearing_area).abs() < 1e-9);
+        // 17-4 bushing in 7075 housing → housing Fbru governs
+        assert_eq!(f.bearing_allowable, 160.0);
+        let shear_area = std::f64::consts::PI * out.od_installed * 0.0625;
+        assert!((f.shear_stress - 2.0 / shear_area).abs() < 1e-9);
+        assert!(out.candidates.iter().any(|c| c.name == "flange_shear"));
+        assert_eq!(out.neck_wall, Some(out.geometry.wall_neck));
+    }
+
+    #[test]
+    fn bearing_seat_uses_the_resolved_bore() {
+        let out = solve(json!({ "axialLoad": 2000.0, "boreNominal": 0.52 }));
+        let f   = out.flange.as_ref().expect("flanged");
+        assert_eq!(out.tolerance.bore.nominal, 0.52);
+        let bearing_area = std::f64::consts::PI / 4.0 * (0.75f64.powi(2) - 0.52f64.powi(2));
+        assert!((f.bearing_area - bearing_area).abs() < 1e-12);
+    }
+
+    #[test]
+    fn axial_load_defaults_to_install_force() {
+        let out = solve(json!({}));
+        let f   = out.flange.unwrap();
+        assert_eq!(f.axial_load_source, "install_force");
+        assert!((f.axial_load - out.physics.install_force).abs() < 1e-9);
+    }
+
+    #[test]
+    fn thin_neck_is_flagged_without_governing() {
+        let out = solve(json!({ "idBushing": 0.48, "minWallNeck": 0.02, "minWallStraight": 0.005 }));
+        assert!(out.warning_codes.iter().any(|w| w.code == BushingWarningCode::NeckWallBelowMin));
+        let neck = out.candidates.iter().find(|c| c.name == "neck_wall").unwrap();
+        assert!(neck.margin < 0.0 && neck.kind == CandidateKind::Geometry);
+        assert_ne!(out.governing.name, "neck_wall");
+    }
+
+    #[test]
+    fn straight_bushing_has_no_flange() {
+        let out = solve(json!({ "bushingType": "straight" }));
+        assert!(out.flange.is_none());
+        assert!(out.neck_wall.is_none());
+    }
+}
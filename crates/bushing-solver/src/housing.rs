@@ -0,0 +1,94 @@
+/// Equivalent circular housing for lugs and plates: area-equivalent OD from the
+/// edge distance, lug width and lateral hole offset, with the psi compliance
+/// correction for short edge ligaments.
+/// Ported from solveEngine.ts (D_equivalent / psi / lambda).
+use crate::types::*;
+
+/// Material beyond this many bore diameters from the hole centre no longer
+/// stiffens the housing.
+const SATURATION_DIAMETERS: f64 = 2.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct EquivalentHousing {
+    /// Effective width across the hole, limited by the nearer side under an offset.
+    pub w_eff: f64,
+    pub e_eff: f64,
+    pub d_equivalent: f64,
+    /// Edge ligament ratio e_eff / (D_eq / 2), capped at 1.
+    pub lambda: f64,
+    /// Housing compliance multiplier, 1 + 0.2·(1 − lambda).
+    pub psi: f64,
+    /// Entered width or edge distance exceeds the stiffening radius.
+    pub saturated: bool,
+}
+
+/// Side-to-side half-width available on the near side of an offset hole.
+fn near_half_width(input: &BushingInput) -> f64 {
+    input.housing_width / 2.0 - input.hole_offset.unwrap_or(0.0).abs()
+}
+
+pub fn equivalent_housing(input: &BushingInput, bore_dia: f64) -> EquivalentHousing {
+    let r_sat  = SATURATION_DIAMETERS * bore_dia;
+    let near_w = 2.0 * near_half_width(input).max(0.0);
+    let w_eff  = near_w.min(2.0 * r_sat);
+    let e_eff  = input.edge_dist.min(r_sat);
+
+    // Rectangle of w_eff × 2·e_eff about the hole, less the hole, as an annulus
+    let area         = (w_eff * 2.0 * e_eff - std::f64::consts::PI * bore_dia.powi(2) / 4.0).max(1e-6);
+    let d_equivalent = (4.0 * area / std::f64::consts::PI + bore_dia.powi(2)).sqrt();
+    let lambda       = (e_eff / (d_equivalent / 2.0)).min(1.0);
+    let psi          = 1.0 + 0.2 * (1.0 - lambda);
+
+    EquivalentHousing {
+        w_eff,
+        e_eff,
+        d_equivalent,
+        lambda,
+        psi,
+        saturated: near_w > w_eff || input.edge_dist > e_eff,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::solve_with;
+    use serde_json::json;
+
+    #[test]
+    fn area_equivalent_diameter() {
+        let out = solve_with(json!({}));
+        let area = 1.5 * 2.0 - std::f64::consts::PI * 0.25 / 4.0;
+        let d_eq = (4.0 * area / std::f64::consts::PI + 0.25).sqrt();
+        assert!((out.lame.d_equivalent - d_eq).abs() < 1e-12);
+        assert_eq!(out.lame.effective_od_housing, out.lame.d_equivalent);
+        assert_eq!(out.lame.lambda, 1.0);
+        assert_eq!(out.lame.psi, 1.0);
+        assert!(!out.geometry.is_saturation_active);
+    }
+
+    #[test]
+    fn narrow_lug_with_short_edge_is_more_compliant() {
+        let wide   = solve_with(json!({ "housingWidth": 2.0 }));
+        let narrow = solve_with(json!({ "housingWidth": 0.7, "edgeDist": 0.3 }));
+        assert!(narrow.lame.lambda < 1.0);
+        assert!(narrow.lame.psi > 1.0);
+        assert!(narrow.pressure < wide.pressure);
+    }
+
+    #[test]
+    fn offset_hole_uses_near_side_width() {
+        let centred = solve_with(json!({}));
+        let offset  = solve_with(json!({ "holeOffset": -0.25 }));
+        assert!((offset.lame.w_eff - 1.0).abs() < 1e-12);
+        assert!(offset.lame.d_equivalent < centred.lame.d_equivalent);
+        assert!(offset.pressure < centred.pressure);
+    }
+
+    #[test]
+    fn large_plate_saturates() {
+        let out = solve_with(json!({ "housingWidth": 6.0, "edgeDist": 3.0 }));
+        assert!(out.geometry.is_saturation_active);
+        assert_eq!(out.lame.w_eff, 2.0);
+        assert_eq!(out.lame.e_eff, 1.0);
+    }
+}
@@ -3,268 +3,551 @@ use serde_json::Value;
 
 use crate::{
     error::BushingError,
-    types::*,
+    migrate::{self, CURRENT_VERSION, VERSION_KEY},
+    reader::{Context, FieldShape, Kind, Reader},
+    types::{BushingWarningCode as Code, *},
+    units::{self, Convert, Scale},
 };
 
-/// Flexible raw input — accepts camelCase, snake_case, and legacy aliases.
+/// Default minimum walls (inches).
+const DEFAULT_MIN_WALL_STRAIGHT: f64 = 0.010;
+const DEFAULT_MIN_WALL_NECK: f64 = 0.005;
+
+/// Accepted strings for enum fields shared by several keys.
+const CS_MODES: &[&str] = &["depth_angle", "dia_angle", "dia_depth"];
+const TOLERANCE_MODES: &[&str] = &["nominal_tol", "limits"];
+const UNIT_SYSTEMS: &[&str] = &["imperial", "metric", "si", "mm"];
+
+/// Flexible raw input — accepts camelCase and snake_case keys; documents
+/// from older schema versions (legacy aliases) are migrated first.
 /// Use `serde_json::Value` internally to handle the wide alias surface.
 #[derive(Debug, Deserialize)]
 pub struct BushingInputRaw(pub Value);
 
 impl BushingInputRaw {
     pub fn normalize(self) -> Result<BushingInput, BushingError> {
-        let v = &self.0;
-
-        let f = |keys: &[&str]| -> Option<f64> {
-            keys.iter().find_map(|k| v.get(k)?.as_f64())
-        };
-        let s = |keys: &[&str]| -> Option<String> {
-            keys.iter().find_map(|k| v.get(k)?.as_str().map(str::to_owned))
-        };
-        let b = |keys: &[&str]| -> Option<bool> {
-            keys.iter().find_map(|k| v.get(k)?.as_bool())
-        };
-
-        let units = s(&["units"]).unwrap_or_else(|| "imperial".into());
-        let bore_dia = f(&["boreDia", "bore_dia"])
-            .ok_or_else(|| BushingError::MissingField("boreDia"))?;
-        let id_bushing = f(&["idBushing", "id_bushing", "bushID"])
-            .ok_or_else(|| BushingError::MissingField("idBushing"))?;
-        let interference = f(&["interference"])
-            .ok_or_else(|| BushingError::MissingField("interference"))?;
-        let housing_len = f(&["housingLen", "housing_len"])
-            .ok_or_else(|| BushingError::MissingField("housingLen"))?;
-        let housing_width = f(&["housingWidth", "housing_width"])
-            .ok_or_else(|| BushingError::MissingField("housingWidth"))?;
-        let edge_dist = f(&["edgeDist", "edge_dist"])
-            .ok_or_else(|| BushingError::MissingField("edgeDist"))?;
-
-        let bushing_type = match s(&["bushingType", "bushing_type"])
-            .unwrap_or_default()
-            .as_str()
-        {
-            "flanged"      => BushingType::Flanged,
-            "countersink"  => BushingType::Countersink,
-            _              => BushingType::Straight,
-        };
-
-        let id_type = match s(&["idType", "id_type"]).unwrap_or_default().as_str() {
-            "countersink" => IdType::Countersink,
-            _             => IdType::Straight,
-        };
-
-        let parse_cs_mode = |keys: &[&str]| match s(keys).unwrap_or_default().as_str() {
+        self.normalize_with(InputMode::Lenient).map(|(input, _)| input)
+    }
+
+    /// Normalize, reporting unrecognised keys, enum strings outside the
+    /// accepted set, aliases given with differing values and values of the
+    /// wrong type. Strict mode fails with every finding as a
+    /// [`BushingError::Schema`]; lenient mode falls back on the defaults and
+    /// returns the findings as warnings. Keys renamed by the schema migration
+    /// are reported at info level in both modes.
+    pub fn normalize_with(self, mode: InputMode) -> Result<(BushingInput, Vec<ValidationIssue>), BushingError> {
+        let (doc, report) = migrate::migrate(self.0)?;
+        let ctx = Context::new(match mode {
+            InputMode::Strict  => WarningSeverity::Error,
+            InputMode::Lenient => WarningSeverity::Warning,
+        });
+        let (input, missing) = parse(&doc, &ctx)?;
+
+        let mut issues: Vec<ValidationIssue> = report
+            .changes
+            .into_iter()
+            .map(|c| ValidationIssue {
+                pointer: c.pointer,
+                code: Code::InputMigrated,
+                severity: WarningSeverity::Info,
+                message: c.message,
+                suggestion: format!("Save the document as {VERSION_KEY} {CURRENT_VERSION}."),
+            })
+            .collect();
+        issues.extend(ctx.issues.take());
+        if mode == InputMode::Strict {
+            issues.extend(missing.iter().map(|keys| keys[0]).map(|k| ValidationIssue {
+                pointer: format!("/{k}"),
+                code: Code::InputInvalid,
+                severity: WarningSeverity::Error,
+                message: format!("{k} is required"),
+                suggestion: format!("Enter {k}."),
+            }));
+            if issues.iter().any(|i| i.severity == WarningSeverity::Error) {
+                return Err(BushingError::Schema(issues));
+            }
+        }
+        if let Some(keys) = missing.first() {
+            return Err(BushingError::MissingField(keys[0]));
+        }
+        Ok((input, issues))
+    }
+}
+
+/// Every field the parser reads and the required keys (with their aliases),
+/// for [`crate::schema::json_schema`].
+pub(crate) fn describe() -> (Vec<FieldShape>, Vec<Vec<&'static str>>) {
+    let ctx = Context::recording();
+    let required = parse(&Value::Null, &ctx).map(|(_, missing)| missing).unwrap_or_default();
+    let fields   = ctx.fields.map(|f| f.into_inner()).unwrap_or_default();
+    (fields, required)
+}
+
+/// Read `v` into a `BushingInput`, converting to internal units. Absent
+/// required fields are returned (with their aliases) rather than failing, so
+/// every key is still checked.
+fn parse(v: &Value, ctx: &Context) -> Result<(BushingInput, Vec<Vec<&'static str>>), BushingError> {
+    let r = Reader::root(v, ctx);
+
+    let f = |keys: &[&'static str]| r.f(keys);
+    let s = |keys: &[&'static str]| r.s(keys);
+    let b = |keys: &[&'static str]| r.b(keys);
+    let u = |keys: &[&'static str]| r.u(keys);
+    let e = |keys: &[&'static str], allowed: &[&str]| r.choice(keys, allowed);
+
+    // Required fields are collected so the remaining keys are still checked
+    let mut missing = vec![];
+    let mut required = |keys: &[&'static str]| {
+        f(keys).unwrap_or_else(|| {
+            missing.push(keys.to_vec());
+            0.0
+        })
+    };
+
+    let _version = u(&[VERSION_KEY]);
+    let units = units::parse(&r.choice_folded(&["units"], UNIT_SYSTEMS));
+    let bore_dia      = required(&["boreDia", "bore_dia"]);
+    let id_bushing    = required(&["idBushing", "id_bushing"]);
+    let interference  = required(&["interference"]);
+    let housing_len   = required(&["housingLen", "housing_len"]);
+    let housing_width = required(&["housingWidth", "housing_width"]);
+    let edge_dist     = required(&["edgeDist", "edge_dist"]);
+
+    let bushing_type = match e(&["bushingType", "bushing_type"], &["straight", "flanged", "countersink"]).as_str() {
+        "flanged"      => BushingType::Flanged,
+        "countersink"  => BushingType::Countersink,
+        _              => BushingType::Straight,
+    };
+
+    let id_type = match e(&["idType", "id_type"], &["straight", "countersink"]).as_str() {
+        "countersink" => IdType::Countersink,
+        _             => IdType::Straight,
+    };
+
+    // Nested idCS / odCS objects back-fill the flat countersink fields
+    let id_cs = r.child(&["idCS", "id_cs"]).map(|c| parse_cs_input(&c));
+    let od_cs = r.child(&["odCS", "od_cs"]).map(|c| parse_cs_input(&c));
+    let id_nested = |pick: fn(&CountersinkInput) -> Option<f64>| id_cs.as_ref().and_then(pick);
+    let od_nested = |pick: fn(&CountersinkInput) -> Option<f64>| od_cs.as_ref().and_then(pick);
+
+    let parse_cs_mode = |keys: &[&'static str], nested: &Option<CountersinkInput>| {
+        let mode = Some(e(keys, CS_MODES)).filter(|m| !m.is_empty());
+        match mode.or_else(|| nested.as_ref().and_then(|c| c.def_type.clone())).unwrap_or_default().as_str() {
             "dia_angle" => CsMode::DiaAngle,
             "dia_depth" => CsMode::DiaDepth,
             _           => CsMode::DepthAngle,
-        };
-
-        let cs_mode     = parse_cs_mode(&["csMode",    "cs_mode"]);
-        let ext_cs_mode = parse_cs_mode(&["extCsMode", "ext_cs_mode"]);
-
-        let bore_tol_mode = match s(&["boreTolMode", "bore_tol_mode"])
-            .unwrap_or_default()
-            .as_str()
-        {
-            "limits" => ToleranceMode::Limits,
-            _        => ToleranceMode::NominalTol,
-        };
-
-        let interference_tol_mode = match s(&["interferenceTolMode", "interference_tol_mode"])
-            .unwrap_or_default()
-            .as_str()
-        {
-            "limits" => ToleranceMode::Limits,
-            _        => ToleranceMode::NominalTol,
-        };
-
-        let interference_policy = parse_interference_policy(v);
-        let bore_capability     = parse_bore_capability(v);
-
-        let end_constraint = match s(&["endConstraint", "end_constraint"])
-            .unwrap_or_default()
-            .as_str()
-        {
-            "one_end"   => EndConstraint::OneEnd,
-            "both_ends" => EndConstraint::BothEnds,
-            _           => EndConstraint::Free,
-        };
-
-        let process_route_id = match s(&["processRouteId", "process_route_id"])
-            .unwrap_or_default()
-            .as_str()
-        {
-            "press_fit_finish_ream"   => BushingProcessRouteId::PressFitFinishReam,
-            "line_ream_repair"        => BushingProcessRouteId::LineReamRepair,
-            "thermal_assist_install"  => BushingProcessRouteId::ThermalAssistInstall,
-            "bonded_joint"            => BushingProcessRouteId::BondedJoint,
-            _                         => BushingProcessRouteId::PressFitOnly,
-        };
-
-        let standards_basis = match s(&["standardsBasis", "standards_basis"])
-            .unwrap_or_default()
-            .as_str()
-        {
-            "faa_ac_43_13" => BushingStandardsBasis::FaaAc4313,
-            "nas_ms"       => BushingStandardsBasis::NasMs,
-            "sae_ams"      => BushingStandardsBasis::SaeAms,
-            "oem_srm"      => BushingStandardsBasis::OemSrm,
-            _              => BushingStandardsBasis::ShopDefault,
-        };
-
-        let criticality = match s(&["criticality"])
-            .unwrap_or_default()
-            .as_str()
-        {
-            "primary_structure" => BushingCriticality::PrimaryStructure,
-            "repair"            => BushingCriticality::Repair,
-            _                   => BushingCriticality::General,
-        };
-
-        let load_spectrum = match s(&["loadSpectrum", "load_spectrum"])
-            .unwrap_or_default()
-            .as_str()
-        {
-            "oscillating" => BushingLoadSpectrum::Oscillating,
-            "rotating"    => BushingLoadSpectrum::Rotating,
-            _             => BushingLoadSpectrum::Static,
-        };
-
-        let lubrication_mode = match s(&["lubricationMode", "lubrication_mode"])
-            .unwrap_or_default()
-            .as_str()
-        {
-            "greased"    => BushingLubricationMode::Greased,
-            "oiled"      => BushingLubricationMode::Oiled,
-            "solid_film" => BushingLubricationMode::SolidFilm,
-            _            => BushingLubricationMode::Dry,
-        };
-
-        let contamination_level = match s(&["contaminationLevel", "contamination_level"])
-            .unwrap_or_default()
-            .as_str()
-        {
-            "shop"     => BushingContaminationLevel::Shop,
-            "dirty"    => BushingContaminationLevel::Dirty,
-            "abrasive" => BushingContaminationLevel::Abrasive,
-            _          => BushingContaminationLevel::Clean,
-        };
-
-        Ok(BushingInput {
-            units,
-            bore_dia,
-            id_bushing,
-            interference,
-            bore_tol_mode,
-            bore_nominal:              f(&["boreNominal",    "bore_nominal"]),
-            bore_tol_plus:             f(&["boreTolPlus",    "bore_tol_plus"]),
-            bore_tol_minus:            f(&["boreTolMinus",   "bore_tol_minus"]),
-            bore_lower:                f(&["boreLower",      "bore_lower"]),
-            bore_upper:                f(&["boreUpper",      "bore_upper"]),
-            interference_tol_mode,
-            interference_nominal:      f(&["interferenceNominal",   "interference_nominal"]),
-            interference_tol_plus:     f(&["interferenceTolPlus",   "interference_tol_plus"]),
-            interference_tol_minus:    f(&["interferenceTolMinus",  "interference_tol_minus"]),
-            interference_lower:        f(&["interferenceLower",     "interference_lower"]),
-            interference_upper:        f(&["interferenceUpper",     "interference_upper"]),
-            interference_policy,
-            bore_capability,
-            enforce_interference_tolerance: b(&["enforceInterferenceTolerance", "enforce_interference_tolerance"]).unwrap_or(false),
-            lock_bore_for_interference:     b(&["lockBoreForInterference",       "lock_bore_for_interference"]).unwrap_or(false),
-            housing_len,
-            housing_width,
-            edge_dist,
-            bushing_type,
-            id_type,
-            cs_mode,
-            cs_dia:                    f(&["csDia",    "cs_dia"]).unwrap_or(0.0),
-            cs_depth:                  f(&["csDepth",  "cs_depth"]).unwrap_or(0.0),
-            cs_depth_tol_plus:         f(&["csDepthTolPlus",  "cs_depth_tol_plus"]),
-            cs_depth_tol_minus:        f(&["csDepthTolMinus", "cs_depth_tol_minus"]),
-            cs_angle:                  f(&["csAngle",  "cs_angle"]).unwrap_or(100.0),
-            ext_cs_mode,
-            ext_cs_dia:                f(&["extCsDia",   "ext_cs_dia"]).unwrap_or(0.0),
-            ext_cs_depth:              f(&["extCsDepth", "ext_cs_depth"]).unwrap_or(0.0),
-            ext_cs_depth_tol_plus:     f(&["extCsDepthTolPlus",  "ext_cs_depth_tol_plus"]),
-            ext_cs_depth_tol_minus:    f(&["extCsDepthTolMinus", "ext_cs_depth_tol_minus"]),
-            ext_cs_angle:              f(&["extCsAngle", "ext_cs_angle"]).unwrap_or(100.0),
-            flange_dia:                f(&["flangeDia",  "flange_dia"]),
-            flange_od:                 f(&["flangeOd",   "flange_od"]),
-            flange_thk:                f(&["flangeThk",  "flange_thk"]),
-            mat_housing:               s(&["matHousing", "mat_housing"]).unwrap_or_else(|| "Al_7075_T6".into()),
-            mat_bushing:               s(&["matBushing", "mat_bushing"]).unwrap_or_else(|| "SS_17_4_PH".into()),
-            friction:                  f(&["friction"]).unwrap_or(0.15),
-            d_t:                       f(&["dT", "d_t"]).unwrap_or(0.0),
-            assembly_housing_temperature: f(&["assemblyHousingTemperature", "assembly_housing_temperature", "t1"]),
-            assembly_bushing_temperature: f(&["assemblyBushingTemperature", "assembly_bushing_temperature", "t2"]),
-            process_route_id,
-            standards_basis,
-            standards_revision:        s(&["standardsRevision",  "standards_revision"]),
-            process_spec:              s(&["processSpec",         "process_spec"]),
-            approval_notes:            s(&["approvalNotes",       "approval_notes"]),
-            criticality,
-            min_wall_straight:         f(&["minWallStraight",  "min_wall_straight"]).unwrap_or(0.010),
-            min_wall_neck:             f(&["minWallNeck",      "min_wall_neck"]).unwrap_or(0.005),
-            end_constraint,
-            load:                      f(&["load"]),
-            edge_load_angle_deg:       f(&["edgeLoadAngleDeg", "edge_load_angle_deg", "thetaDeg"]),
-            service_temperature_hot:   f(&["serviceTemperatureHot",  "service_temperature_hot"]),
-            service_temperature_cold:  f(&["serviceTemperatureCold", "service_temperature_cold"]),
-            finish_ream_allowance:     f(&["finishReamAllowance",    "finish_ream_allowance"]),
-            wear_allowance:            f(&["wearAllowance",          "wear_allowance"]),
-            load_spectrum,
-            oscillation_angle_deg:     f(&["oscillationAngleDeg",    "oscillation_angle_deg"]),
-            oscillation_freq_hz:       f(&["oscillationFreqHz",      "oscillation_freq_hz"]),
-            duty_cycle_pct:            f(&["dutyCyclePct",           "duty_cycle_pct"]),
-            lubrication_mode,
-            contamination_level,
-            surface_roughness_ra_um:   f(&["surfaceRoughnessRaUm", "surface_roughness_ra_um"]),
-            shaft_hardness_hrc:        f(&["shaftHardnessHrc",     "shaft_hardness_hrc"]),
-            misalignment_deg:          f(&["misalignmentDeg",      "misalignment_deg"]),
-            id_cs:  parse_cs_input(&v["idCS"]).or_else(|| parse_cs_input(&v["id_cs"])),
-            od_cs:  parse_cs_input(&v["odCS"]).or_else(|| parse_cs_input(&v["od_cs"])),
-        })
+        }
+    };
+
+    let cs_mode     = parse_cs_mode(&["csMode",    "cs_mode"], &id_cs);
+    let ext_cs_mode = parse_cs_mode(&["extCsMode", "ext_cs_mode"], &od_cs);
+
+    let bore_tol_mode = match e(&["boreTolMode", "bore_tol_mode"], TOLERANCE_MODES).as_str() {
+        "limits" => ToleranceMode::Limits,
+        _        => ToleranceMode::NominalTol,
+    };
+
+    let interference_tol_mode = match e(&["interferenceTolMode", "interference_tol_mode"], TOLERANCE_MODES).as_str() {
+        "limits" => ToleranceMode::Limits,
+        _        => ToleranceMode::NominalTol,
+    };
+
+    let analysis_mode = match e(&["analysisMode", "analysis_mode"], &["nominal", "worst_case", "statistical"]).as_str() {
+        "worst_case"  => BushingAnalysisMode::WorstCase,
+        "statistical" => BushingAnalysisMode::Statistical,
+        _             => BushingAnalysisMode::Nominal,
+    };
+
+    let interference_policy = r.child(&["interferencePolicy", "interference_policy"]).map(|c| parse_interference_policy(&c)).unwrap_or_default();
+    let bore_capability     = r.child(&["boreCapability", "bore_capability"]).map(|c| parse_bore_capability(&c)).unwrap_or_default();
+
+    let end_constraint = match e(&["endConstraint", "end_constraint"], &["free", "one_end", "both_ends"]).as_str() {
+        "one_end"   => EndConstraint::OneEnd,
+        "both_ends" => EndConstraint::BothEnds,
+        _           => EndConstraint::Free,
+    };
+
+    let failure_criterion = match e(&["failureCriterion", "failure_criterion"], &["von_mises", "max_principal", "tresca"]).as_str() {
+        "max_principal" => FailureCriterion::MaxPrincipal,
+        "tresca"        => FailureCriterion::Tresca,
+        _               => FailureCriterion::VonMises,
+    };
+
+    let allowable_basis = match e(&["allowableBasis", "allowable_basis"], &["yield", "ultimate", "bearing"]).as_str() {
+        "ultimate" => AllowableBasis::Ultimate,
+        "bearing"  => AllowableBasis::Bearing,
+        _          => AllowableBasis::Yield,
+    };
+
+    let process_route_id = match e(&["processRouteId", "process_route_id"], &["press_fit_only", "press_fit_finish_ream", "line_ream_repair", "thermal_assist_install", "bonded_joint"]).as_str() {
+        "press_fit_finish_ream"   => BushingProcessRouteId::PressFitFinishReam,
+        "line_ream_repair"        => BushingProcessRouteId::LineReamRepair,
+        "thermal_assist_install"  => BushingProcessRouteId::ThermalAssistInstall,
+        "bonded_joint"            => BushingProcessRouteId::BondedJoint,
+        _                         => BushingProcessRouteId::PressFitOnly,
+    };
+
+    let standards_basis = match e(&["standardsBasis", "standards_basis"], &["shop_default", "faa_ac_43_13", "nas_ms", "sae_ams", "oem_srm"]).as_str() {
+        "faa_ac_43_13" => BushingStandardsBasis::FaaAc4313,
+        "nas_ms"       => BushingStandardsBasis::NasMs,
+        "sae_ams"      => BushingStandardsBasis::SaeAms,
+        "oem_srm"      => BushingStandardsBasis::OemSrm,
+        _              => BushingStandardsBasis::ShopDefault,
+    };
+
+    let criticality = match e(&["criticality"], &["general", "primary_structure", "repair"]).as_str() {
+        "primary_structure" => BushingCriticality::PrimaryStructure,
+        "repair"            => BushingCriticality::Repair,
+        _                   => BushingCriticality::General,
+    };
+
+    let load_spectrum = match e(&["loadSpectrum", "load_spectrum"], &["static", "oscillating", "rotating"]).as_str() {
+        "oscillating" => BushingLoadSpectrum::Oscillating,
+        "rotating"    => BushingLoadSpectrum::Rotating,
+        _             => BushingLoadSpectrum::Static,
+    };
+
+    let lubrication_mode = match e(&["lubricationMode", "lubrication_mode"], &["dry", "greased", "oiled", "solid_film"]).as_str() {
+        "greased"    => BushingLubricationMode::Greased,
+        "oiled"      => BushingLubricationMode::Oiled,
+        "solid_film" => BushingLubricationMode::SolidFilm,
+        _            => BushingLubricationMode::Dry,
+    };
+
+    let material_basis = match r.choice_folded(&["materialBasis", "material_basis"], &["a", "b", "s"]).as_str() {
+        "a" => MaterialBasis::A,
+        "b" => MaterialBasis::B,
+        _   => MaterialBasis::S,
+    };
+
+    // Inline materials: a `customMaterials` list, or an object in place of a material id
+    let mut custom_materials = r
+        .typed(&["customMaterials", "custom_materials"], Kind::Array, Value::as_array)
+        .map(|a| a.iter().map(|m| parse_material(m, "custom")).collect::<Result<Vec<_>, _>>())
+        .transpose()?
+        .unwrap_or_default();
+    let mut material_id = |keys: &[&'static str], fallback: &str, default: &str| -> Result<String, BushingError> {
+        match r.typed(keys, Kind::IdOrObject, |x| (x.is_string() || x.is_object()).then_some(x)) {
+            Some(Value::String(id)) => Ok(id.clone()),
+            Some(obj) => {
+                let m  = parse_material(obj, fallback)?;
+                let id = m.id.clone();
+                custom_materials.retain(|c| c.id != id);
+                custom_materials.push(m);
+                Ok(id)
+            }
+            None => Ok(default.into()),
+        }
+    };
+    let mat_housing = material_id(&["matHousing", "mat_housing"], "inline_housing", "Al_7075_T6")?;
+    let mat_bushing = material_id(&["matBushing", "mat_bushing"], "inline_bushing", "SS_17_4_PH")?;
+
+    let contamination_level = match e(&["contaminationLevel", "contamination_level"], &["clean", "shop", "dirty", "abrasive"]).as_str() {
+        "shop"     => BushingContaminationLevel::Shop,
+        "dirty"    => BushingContaminationLevel::Dirty,
+        "abrasive" => BushingContaminationLevel::Abrasive,
+        _          => BushingContaminationLevel::Clean,
+    };
+
+    let mut input = BushingInput {
+        units,
+        bore_dia,
+        id_bushing,
+        interference,
+        bore_tol_mode,
+        bore_nominal:              f(&["boreNominal",    "bore_nominal"]),
+        bore_tol_plus:             f(&["boreTolPlus",    "bore_tol_plus"]),
+        bore_tol_minus:            f(&["boreTolMinus",   "bore_tol_minus"]),
+        bore_lower:                f(&["boreLower",      "bore_lower"]),
+        bore_upper:                f(&["boreUpper",      "bore_upper"]),
+        interference_tol_mode,
+        interference_nominal:      f(&["interferenceNominal",   "interference_nominal"]),
+        interference_tol_plus:     f(&["interferenceTolPlus",   "interference_tol_plus"]),
+        interference_tol_minus:    f(&["interferenceTolMinus",  "interference_tol_minus"]),
+        interference_lower:        f(&["interferenceLower",     "interference_lower"]),
+        interference_upper:        f(&["interferenceUpper",     "interference_upper"]),
+        interference_policy,
+        bore_capability,
+        enforce_interference_tolerance: b(&["enforceInterferenceTolerance", "enforce_interference_tolerance"]).unwrap_or(false),
+        lock_bore_for_interference:     b(&["lockBoreForInterference",       "lock_bore_for_interference"]).unwrap_or(false),
+        housing_len,
+        housing_width,
+        edge_dist,
+        hole_offset:               f(&["holeOffset", "hole_offset"]),
+        bushing_type,
+        id_type,
+        cs_mode,
+        cs_dia:                    f(&["csDia",    "cs_dia"]).or(id_nested(|c| c.dia)).unwrap_or(0.0),
+        cs_depth:                  f(&["csDepth",  "cs_depth"]).or(id_nested(|c| c.depth)).unwrap_or(0.0),
+        cs_depth_tol_plus:         f(&["csDepthTolPlus",  "cs_depth_tol_plus"]),
+        cs_depth_tol_minus:        f(&["csDepthTolMinus", "cs_depth_tol_minus"]),
+        cs_angle:                  f(&["csAngle",  "cs_angle"]).or(id_nested(|c| c.angle_deg)).unwrap_or(100.0),
+        ext_cs_mode,
+        ext_cs_dia:                f(&["extCsDia",   "ext_cs_dia"]).or(od_nested(|c| c.dia)).unwrap_or(0.0),
+        ext_cs_depth:              f(&["extCsDepth", "ext_cs_depth"]).or(od_nested(|c| c.depth)).unwrap_or(0.0),
+        ext_cs_depth_tol_plus:     f(&["extCsDepthTolPlus",  "ext_cs_depth_tol_plus"]),
+        ext_cs_depth_tol_minus:    f(&["extCsDepthTolMinus", "ext_cs_depth_tol_minus"]),
+        ext_cs_angle:              f(&["extCsAngle", "ext_cs_angle"]).or(od_nested(|c| c.angle_deg)).unwrap_or(100.0),
+        flange_dia:                f(&["flangeDia",  "flange_dia"]),
+        flange_od:                 f(&["flangeOd",   "flange_od"]),
+        flange_thk:                f(&["flangeThk",  "flange_thk"]),
+        mat_housing,
+        mat_bushing,
+        material_basis,
+        custom_materials,
+        friction:                  f(&["friction"]).unwrap_or(0.15),
+        d_t:                       f(&["dT", "d_t"]).unwrap_or(0.0),
+        assembly_housing_temperature: f(&["assemblyHousingTemperature", "assembly_housing_temperature"]),
+        assembly_bushing_temperature: f(&["assemblyBushingTemperature", "assembly_bushing_temperature"]),
+        assembly_clearance_target: f(&["assemblyClearanceTarget", "assembly_clearance_target"]),
+        process_route_id,
+        standards_basis,
+        standards_revision:        s(&["standardsRevision",  "standards_revision"]),
+        process_spec:              s(&["processSpec",         "process_spec"]),
+        approval_notes:            s(&["approvalNotes",       "approval_notes"]),
+        criticality,
+        min_wall_straight:         f(&["minWallStraight",  "min_wall_straight"]).unwrap_or(DEFAULT_MIN_WALL_STRAIGHT),
+        min_wall_neck:             f(&["minWallNeck",      "min_wall_neck"]).unwrap_or(DEFAULT_MIN_WALL_NECK),
+        end_constraint,
+        failure_criterion,
+        allowable_basis,
+        fitting_factor:            f(&["fittingFactor", "fitting_factor"]),
+        load:                      f(&["load"]),
+        axial_load:                f(&["axialLoad", "axial_load"]),
+        edge_load_angle_deg:       f(&["edgeLoadAngleDeg", "edge_load_angle_deg"]),
+        service_temperature_hot:   f(&["serviceTemperatureHot",  "service_temperature_hot"]),
+        service_temperature_cold:  f(&["serviceTemperatureCold", "service_temperature_cold"]),
+        finish_ream_allowance:     f(&["finishReamAllowance",    "finish_ream_allowance"]),
+        wear_allowance:            f(&["wearAllowance",          "wear_allowance"]),
+        load_spectrum,
+        oscillation_angle_deg:     f(&["oscillationAngleDeg",    "oscillation_angle_deg"]),
+        oscillation_freq_hz:       f(&["oscillationFreqHz",      "oscillation_freq_hz"]),
+        duty_cycle_pct:            f(&["dutyCyclePct",           "duty_cycle_pct"]),
+        lubrication_mode,
+        contamination_level,
+        surface_roughness_ra_um:   f(&["surfaceRoughnessRaUm", "surface_roughness_ra_um"]),
+        shaft_hardness_hrc:        f(&["shaftHardnessHrc",     "shaft_hardness_hrc"]),
+        misalignment_deg:          f(&["misalignmentDeg",      "misalignment_deg"]),
+        id_cs,
+        od_cs,
+        measured_part: r.child(&["measuredPart", "measured_part"]).and_then(|c| parse_measured_part(&c)),
+        analysis_mode,
+        monte_carlo_samples: u(&["monteCarloSamples", "monte_carlo_samples"]).map(|n| n.min(u32::MAX as u64) as u32),
+        monte_carlo_seed:    u(&["monteCarloSeed",    "monte_carlo_seed"]),
+        d_t_std_dev:         f(&["dTStdDev", "d_t_std_dev"]),
+    };
+
+    // Entered values are in `units`; the solver works in inches, ksi, lbf and °F
+    let scale = Scale::to_internal(units);
+    if !scale.is_identity() {
+        input.convert(&scale);
+        input.min_wall_straight = f(&["minWallStraight", "min_wall_straight"]).map_or(DEFAULT_MIN_WALL_STRAIGHT, |w| w * scale.length);
+        input.min_wall_neck     = f(&["minWallNeck",     "min_wall_neck"]).map_or(DEFAULT_MIN_WALL_NECK, |w| w * scale.length);
     }
+
+    r.finish();
+    Ok((input, missing))
 }
 
-fn parse_interference_policy(v: &Value) -> InterferenceEnforcementPolicy {
-    let src = v.get("interferencePolicy")
-        .or_else(|| v.get("interference_policy"))
-        .cloned()
-        .unwrap_or(Value::Null);
-
-    InterferenceEnforcementPolicy {
-        enabled:                src.get("enabled").and_then(|x| x.as_bool()),
-        lock_bore:              src.get("lockBore").or_else(|| src.get("lock_bore")).and_then(|x| x.as_bool()),
-        preserve_bore_nominal:  src.get("preserveBoreNominal").or_else(|| src.get("preserve_bore_nominal")).and_then(|x| x.as_bool()),
-        allow_bore_nominal_shift: src.get("allowBoreNominalShift").or_else(|| src.get("allow_bore_nominal_shift")).and_then(|x| x.as_bool()),
-        max_bore_nominal_shift: src.get("maxBoreNominalShift").or_else(|| src.get("max_bore_nominal_shift")).and_then(|x| x.as_f64()),
+/// snake_case form of a camelCase key (`eKsi` → `e_ksi`).
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for ch in name.chars() {
+        if ch.is_ascii_uppercase() {
+            out.push('_');
+            out.push(ch.to_ascii_lowercase());
+        } else {
+            out.push(ch);
+        }
     }
+    out
 }
 
-fn parse_bore_capability(v: &Value) -> BoreProcessCapability {
-    let src = v.get("boreCapability")
-        .or_else(|| v.get("bore_capability"))
-        .cloned()
-        .unwrap_or(Value::Null);
-
-    BoreProcessCapability {
-        mode:                       src.get("mode").and_then(|x| x.as_str()).map(str::to_owned),
-        min_achievable_tol_width:   src.get("minAchievableTolWidth").or_else(|| src.get("min_achievable_tol_width")).and_then(|x| x.as_f64()),
-        max_recommended_tol_width:  src.get("maxRecommendedTolWidth").or_else(|| src.get("max_recommended_tol_width")).and_then(|x| x.as_f64()),
-        preferred_it_class:         src.get("preferredItClass").or_else(|| src.get("preferred_it_class")).and_then(|x| x.as_str()).map(str::to_owned),
+/// camelCase form of a snake_case key (`alpha_u_f` → `alphaUF`).
+pub(crate) fn camel_case(name: &str) -> String {
+    let mut out   = String::with_capacity(name.len());
+    let mut upper = false;
+    for ch in name.chars() {
+        match ch {
+            '_'          => upper = true,
+            _ if upper   => {
+                out.push(ch.to_ascii_uppercase());
+                upper = false;
+            }
+            _            => out.push(ch),
+        }
     }
+    out
+}
+
+fn snake_keys(v: &Value) -> Value {
+    match v {
+        Value::Object(m) => Value::Object(m.iter().map(|(k, x)| (snake_case(k), snake_keys(x))).collect()),
+        Value::Array(a)  => Value::Array(a.iter().map(snake_keys).collect()),
+        _                => v.clone(),
+    }
+}
+
+/// Inline material in library units and field names (camelCase accepted).
+/// `id` defaults to `fallback_id` and `name` to the id.
+fn parse_material(v: &Value, fallback_id: &str) -> Result<MaterialProps, BushingError> {
+    let mut m = snake_keys(v);
+    if m.get("id").is_none() {
+        m["id"] = Value::from(fallback_id);
+    }
+    if m.get("name").is_none() {
+        m["name"] = m["id"].clone();
+    }
+    let m: MaterialProps = serde_json::from_value(m)
+        .map_err(|e| BushingError::InvalidInput(format!("inline material: {e}")))?;
+    crate::materials::validate(&m)?;
+    Ok(m)
+}
+
+fn parse_interference_policy(r: &Reader<'_>) -> InterferenceEnforcementPolicy {
+    let policy = InterferenceEnforcementPolicy {
+        enabled:                  r.b(&["enabled"]),
+        lock_bore:                r.b(&["lockBore", "lock_bore"]),
+        preserve_bore_nominal:    r.b(&["preserveBoreNominal", "preserve_bore_nominal"]),
+        allow_bore_nominal_shift: r.b(&["allowBoreNominalShift", "allow_bore_nominal_shift"]),
+        max_bore_nominal_shift:   r.f(&["maxBoreNominalShift", "max_bore_nominal_shift"]),
+    };
+    r.finish();
+    policy
+}
+
+fn parse_bore_capability(r: &Reader<'_>) -> BoreProcessCapability {
+    let capability = BoreProcessCapability {
+        mode:                      r.s(&["mode"]),
+        min_achievable_tol_width:  r.f(&["minAchievableTolWidth", "min_achievable_tol_width"]),
+        max_recommended_tol_width: r.f(&["maxRecommendedTolWidth", "max_recommended_tol_width"]),
+        preferred_it_class:        r.s(&["preferredItClass", "preferred_it_class"]),
+    };
+    r.finish();
+    capability
+}
+
+fn parse_cs_input(r: &Reader<'_>) -> CountersinkInput {
+    let cs = CountersinkInput {
+        enabled:   r.b(&["enabled"]),
+        def_type:  Some(r.choice(&["defType", "def_type"], CS_MODES)).filter(|t| !t.is_empty()),
+        dia:       r.f(&["dia"]),
+        depth:     r.f(&["depth"]),
+        angle_deg: r.f(&["angleDeg", "angle_deg"]),
+    };
+    r.finish();
+    cs
+}
+
+fn parse_measured_dimension(r: Option<Reader<'_>>) -> Option<MeasuredDimension> {
+    let r = r?;
+    let readings = r
+        .typed(&["readings"], Kind::Array, Value::as_array)
+        .map(|a| {
+            a.iter()
+                .filter_map(|x| match x {
+                    Value::Number(n) => Some(MeasuredReading { dia: n.as_f64()?, ..Default::default() }),
+                    _ => Some(MeasuredReading {
+                        dia:       x.get("dia")?.as_f64()?,
+                        station:   x.get("station").and_then(|x| x.as_f64()),
+                        angle_deg: x.get("angleDeg").or_else(|| x.get("angle_deg")).and_then(|x| x.as_f64()),
+                    }),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let dimension = MeasuredDimension {
+        actual:    r.f(&["actual"]),
+        readings,
+        tol_plus:  r.f(&["tolPlus", "tol_plus"]).map(|t| t.max(0.0)),
+        tol_minus: r.f(&["tolMinus", "tol_minus"]).map(|t| t.max(0.0)),
+        roundness: r.f(&["roundness"]).map(|t| t.max(0.0)),
+        ra:        r.f(&["ra"]).map(|t| t.max(0.0)),
+    };
+    r.finish();
+    Some(dimension)
 }
 
-fn parse_cs_input(v: &Value) -> Option<CountersinkInput> {
-    if v.is_null() {
-        return None;
+fn parse_measured_part(r: &Reader<'_>) -> Option<MeasuredPart> {
+    let basis = r.choice_folded(&["basis"], &["nominal", "measured"]);
+    let part  = MeasuredPart {
+        enabled:       r.b(&["enabled"]).unwrap_or(false),
+        basis:         if basis == "measured" { basis } else { "nominal".into() },
+        bore:          parse_measured_dimension(r.child(&["bore"])),
+        od:            parse_measured_dimension(r.child(&["od"])),
+        id:            parse_measured_dimension(r.child(&["id"])),
+        edge_dist:     r.f(&["edgeDist", "edge_dist"]),
+        housing_width: r.f(&["housingWidth", "housing_width"]),
+        notes:         r.s(&["notes"]).map(|n| n.trim().to_owned()).filter(|n| !n.is_empty()),
+    };
+    r.finish();
+    Some(part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::raw_with;
+    use serde_json::json;
+
+    #[test]
+    fn lenient_mode_keeps_defaults_and_reports_warnings() {
+        let (input, issues) = raw_with(json!({
+            "bushingType": "flange", "edge_dist": 1.2, "frictionCoef": 0.2,
+            "boreCapability": { "mode": "reamer_fixed", "minAchievableTolWidht": 0.001 },
+        }))
+        .normalize_with(InputMode::Lenient)
+        .unwrap();
+        assert_eq!(input.bushing_type, BushingType::Straight);
+        assert_eq!(input.edge_dist, 1.0);
+        let found: Vec<_> = issues.iter().map(|i| (i.pointer.as_str(), i.code, i.suggestion.as_str())).collect();
+        assert_eq!(
+            found,
+            [
+                ("/edge_dist", Code::InputAliasConflict, "Remove edge_dist; edgeDist takes precedence."),
+                ("/bushingType", Code::InputEnumInvalid, "Did you mean 'flanged'?"),
+                ("/boreCapability/minAchievableTolWidht", Code::InputUnknownKey, "Did you mean 'minAchievableTolWidth'?"),
+                ("/frictionCoef", Code::InputUnknownKey, "Did you mean 'friction'?"),
+            ]
+        );
+        assert!(issues.iter().all(|i| i.severity == WarningSeverity::Warning));
+        // Equal aliases and recognised keys are silent
+        let (_, clean) = raw_with(json!({ "edge_dist": 1.0, "materialBasis": "A" })).normalize_with(InputMode::Lenient).unwrap();
+        assert!(clean.is_empty());
+    }
+
+    #[test]
+    fn strict_mode_fails_with_every_finding() {
+        let mut v = raw_with(json!({ "boreDiam": 0.5, "loadSpectrum": "vibrating", "load": "2000" })).0;
+        v.as_object_mut().unwrap().remove("boreDia");
+        let err = BushingInputRaw(v).normalize_with(InputMode::Strict).unwrap_err();
+        let found: Vec<_> = err.issues().iter().map(|i| (i.pointer.as_str(), i.code)).collect();
+        assert_eq!(
+            found,
+            [
+                ("/loadSpectrum", Code::InputEnumInvalid),
+                ("/load", Code::InputInvalid),
+                ("/boreDiam", Code::InputUnknownKey),
+                ("/boreDia", Code::InputInvalid),
+            ]
+        );
+        assert_eq!(err.issues()[2].suggestion, "Did you mean 'boreDia'?");
+        assert!(err.issues().iter().all(|i| i.severity == WarningSeverity::Error));
+        assert!(raw_with(json!({})).normalize_with(InputMode::Strict).is_ok());
+    }
+
+    #[test]
+    fn legacy_documents_are_migrated_with_info_notes() {
+        let mut v = raw_with(json!({ "bushID": 0.3, "thetaDeg": 30.0 })).0;
+        v.as_object_mut().unwrap().remove("idBushing");
+        let (input, issues) = BushingInputRaw(v.clone()).normalize_with(InputMode::Strict).unwrap();
+        assert_eq!(input.id_bushing, 0.3);
+        assert_eq!(input.edge_load_angle_deg, Some(30.0));
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().all(|i| i.code == Code::InputMigrated && i.severity == WarningSeverity::Info));
+        // At the current version the legacy key is no longer recognised
+        v["schemaVersion"] = json!(CURRENT_VERSION);
+        let err = BushingInputRaw(v).normalize_with(InputMode::Strict).unwrap_err();
+        assert!(err.issues().iter().any(|i| i.pointer == "/bushID" && i.code == Code::InputUnknownKey));
     }
-    Some(CountersinkInput {
-        enabled:   v.get("enabled").and_then(|x| x.as_bool()),
-        def_type:  v.get("defType").or_else(|| v.get("def_type")).and_then(|x| x.as_str()).map(str::to_owned),
-        dia:       v.get("dia").and_then(|x| x.as_f64()),
-        depth:     v.get("depth").and_then(|x| x.as_f64()),
-        angle_deg: v.get("angleDeg").or_else(|| v.get("angle_deg")).and_then(|x| x.as_f64()),
-    })
 }
@@ -1,28 +1,162 @@
+pub mod approval;
+pub mod axial;
+pub mod bearing;
+pub mod candidates;
+pub mod canonical;
+pub mod countersink;
+pub mod criteria;
 pub mod error;
+pub mod fit_band;
+pub mod flange;
+pub mod housing;
 pub mod inputs;
+pub mod ligament;
 pub mod materials;
 pub mod math;
+pub mod measured;
+pub mod migrate;
+pub mod process;
+mod reader;
 pub mod schema;
+pub mod sensitivity;
 pub mod service;
+pub mod sizing;
 pub mod solver;
+pub mod sweep;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod thermal;
+pub mod tolerance;
 pub mod types;
+pub mod units;
 
+pub use approval::RuleBook;
 pub use error::BushingError;
 pub use inputs::BushingInputRaw;
-pub use types::{BushingInput, BushingOutput};
+pub use materials::MaterialLibrary;
+pub use migrate::MigrationReport;
+pub use sensitivity::SensitivityReport;
+pub use sizing::{SizingRequest, SizingResult};
+pub use sweep::{SweepRequest, SweepTable};
+pub use types::{BushingInput, BushingOutput, InputMode};
+
+use types::{BushingWarning, ValidationIssue};
 
 /// Compute bushing fit analysis from raw (flexible-format) JSON input.
 ///
 /// Accepts camelCase, snake_case, and legacy field aliases.
 /// Returns a fully-populated [`BushingOutput`] or a [`BushingError`].
 pub fn compute_bushing(raw: BushingInputRaw) -> Result<BushingOutput, BushingError> {
-    let input = raw.normalize()?;
+    let (input, diagnostics) = raw.normalize_with(InputMode::Lenient)?;
     schema::validate(&input)?;
-    solver::compute(&input)
+    let out = solver::compute(&input)?;
+    finish(out, &input, diagnostics)
 }
 
 /// Convenience entry point for already-normalised input (e.g. from the Tauri IPC bridge).
 pub fn compute_bushing_normalised(input: BushingInput) -> Result<BushingOutput, BushingError> {
     schema::validate(&input)?;
-    solver::compute(&input)
+    let out = solver::compute(&input)?;
+    finish(out, &input, vec![])
+}
+
+/// As [`compute_bushing`], evaluating the approval review against `rules`
+/// (e.g. the built-ins merged with a shop rule file).
+pub fn compute_bushing_with_rules(raw: BushingInputRaw, rules: &RuleBook) -> Result<BushingOutput, BushingError> {
+    let (input, diagnostics) = raw.normalize_with(InputMode::Lenient)?;
+    schema::validate(&input)?;
+    let out = solver::compute_with_rules(&input, rules)?;
+    finish(out, &input, diagnostics)
+}
+
+/// As [`compute_bushing_with_rules`], resolving materials from `library`
+/// (e.g. the built-ins merged with a shop material file) and normalizing the
+/// input in `mode`.
+pub fn compute_bushing_with(
+    raw: BushingInputRaw,
+    mode: InputMode,
+    rules: &RuleBook,
+    library: &MaterialLibrary,
+) -> Result<BushingOutput, BushingError> {
+    let (input, diagnostics) = raw.normalize_with(mode)?;
+    schema::validate(&input)?;
+    let out = solver::compute_with(&input, rules, library)?;
+    finish(out, &input, diagnostics)
+}
+
+/// Echo the submitted `input` (before any measured-part override) and append
+/// lenient-mode input findings to the output warnings.
+fn finish(mut out: BushingOutput, input: &BushingInput, diagnostics: Vec<ValidationIssue>) -> Result<BushingOutput, BushingError> {
+    out.effective_input = Some(canonical::export(input)?);
+    for d in diagnostics {
+        out.warnings.push(d.message.clone());
+        out.warning_codes.push(BushingWarning { code: d.code, message: d.message, severity: d.severity });
+    }
+    Ok(out)
+}
+
+/// Normalize `raw` and return it as the canonical camelCase document
+/// (current schema version, defaults filled in, key-sorted).
+pub fn canonical_input(raw: BushingInputRaw) -> Result<serde_json::Value, BushingError> {
+    canonical::export(&raw.normalize()?)
+}
+
+/// Inverse design: search bore, wall and interference around the materials and
+/// housing in `raw` for designs holding `request.target_margin`. The base
+/// input must itself be valid.
+pub fn size_bushing(raw: BushingInputRaw, request: &SizingRequest) -> Result<SizingResult, BushingError> {
+    size_bushing_with(raw, request, InputMode::Lenient, RuleBook::builtin(), MaterialLibrary::builtin())
+}
+
+/// As [`size_bushing`], normalizing in `mode` and solving against `rules`
+/// with materials from `library`.
+pub fn size_bushing_with(
+    raw: BushingInputRaw,
+    request: &SizingRequest,
+    mode: InputMode,
+    rules: &RuleBook,
+    library: &MaterialLibrary,
+) -> Result<SizingResult, BushingError> {
+    let (input, _) = raw.normalize_with(mode)?;
+    schema::validate(&input)?;
+    sizing::optimize(&input, request, rules, library)
+}
+
+/// Parametric sweep of one or two input fields around `raw`. The base input
+/// must itself be valid; points that are not are reported per row.
+pub fn sweep_bushing(raw: BushingInputRaw, request: &SweepRequest) -> Result<SweepTable, BushingError> {
+    sweep_bushing_with(raw, request, InputMode::Lenient, RuleBook::builtin(), MaterialLibrary::builtin())
+}
+
+/// As [`sweep_bushing`], normalizing in `mode` and solving against `rules`
+/// with materials from `library`.
+pub fn sweep_bushing_with(
+    raw: BushingInputRaw,
+    request: &SweepRequest,
+    mode: InputMode,
+    rules: &RuleBook,
+    library: &MaterialLibrary,
+) -> Result<SweepTable, BushingError> {
+    let (input, _) = raw.normalize_with(mode)?;
+    schema::validate(&input)?;
+    sweep::sweep(&input, request, rules, library)
+}
+
+/// Derivatives of pressure, hoop stresses and governing margin with respect to
+/// each numeric input of `raw`, ranked by normalized influence.
+pub fn bushing_sensitivity(raw: BushingInputRaw) -> Result<SensitivityReport, BushingError> {
+    bushing_sensitivity_with(raw, InputMode::Lenient, RuleBook::builtin(), MaterialLibrary::builtin())
+}
+
+/// As [`bushing_sensitivity`], normalizing in `mode` and solving against
+/// `rules` with materials from `library`.
+pub fn bushing_sensitivity_with(
+    raw: BushingInputRaw,
+    mode: InputMode,
+    rules: &RuleBook,
+    library: &MaterialLibrary,
+) -> Result<SensitivityReport, BushingError> {
+    let (input, _) = raw.normalize_with(mode)?;
+    schema::validate(&input)?;
+    sensitivity::analyze(&input, rules, library)
 }
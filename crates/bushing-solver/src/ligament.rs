@@ -0,0 +1,124 @@
+/// Ligament (net section) check for the housing material between the bore and
+/// the part edge, and the minimum edge distance that keeps it at positive margin.
+use crate::{math::EPS, types::*};
+
+/// Edge-distance search ceiling, as a multiple of the bore radius.
+const SEARCH_LIMIT: f64 = 100.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Ligament {
+    pub sigma: f64,
+    pub ms: f64,
+    /// Minimum centre-to-edge distance for zero margin; `None` when the
+    /// interference pressure alone exceeds the allowable at any distance.
+    pub ed_required: Option<f64>,
+}
+
+/// Applied-load component (kips) directed at the edge. The load angle is
+/// measured from the edge normal; loads pointing away from the edge do not
+/// load the ligament in this model.
+pub(crate) fn edge_load_kips(input: &BushingInput) -> f64 {
+    let load  = input.load.unwrap_or(0.0).abs();
+    let theta = input.edge_load_angle_deg.unwrap_or(0.0).to_radians();
+    load * theta.cos().max(0.0) / 1000.0
+}
+
+/// Ligament stress (ksi) at centre-to-edge distance `e`: the Lamé hoop stress
+/// with the edge standing in for the housing outer radius, plus the edge load
+/// spread over the two net sections (e − r)·t either side of the load line.
+pub fn ligament_stress(pressure: f64, bore_r: f64, e: f64, thickness: f64, load_kips: f64) -> f64 {
+    let e    = e.max(bore_r * (1.0 + 1e-9) + EPS);
+    let hoop = pressure.abs() * (e * e + bore_r * bore_r) / (e * e - bore_r * bore_r);
+    let net  = 2.0 * (e - bore_r) * thickness.max(EPS);
+    hoop + load_kips / net
+}
+
+/// Solve the ligament at the entered edge distance and the edge distance at
+/// which its margin reaches zero. Stress falls monotonically with `e`, so the
+/// requirement is bracketed and bisected.
+pub fn evaluate(input: &BushingInput, mat_h: &MaterialProps, bore_dia: f64, pressure: f64) -> Ligament {
+    let bore_r    = bore_dia / 2.0;
+    let t         = input.housing_len;
+    let load      = edge_load_kips(input);
+    let allowable = mat_h.sy_ksi;
+    let stress    = |e: f64| ligament_stress(pressure, bore_r, e, t, load);
+
+    let sigma = stress(input.edge_dist);
+    let ms    = if sigma > 0.0 { allowable / sigma - 1.0 } else { 999.0 };
+
+    let (mut lo, mut hi) = (bore_r, bore_r * SEARCH_LIMIT);
+    let ed_required = if sigma <= 0.0 {
+        Some(bore_r)
+    } else if stress(hi) > allowable {
+        None
+    } else {
+        for _ in 0..100 {
+            let mid = 0.5 * (lo + hi);
+            if stress(mid) > allowable { lo = mid } else { hi = mid }
+            if hi - lo < 1e-9 {
+                break;
+            }
+        }
+        Some(hi)
+    };
+    Ligament { sigma, ms, ed_required }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{input_with, solve_with};
+    use serde_json::json;
+
+    #[test]
+    fn required_edge_distance_zeroes_the_margin() {
+        let out = solve_with(json!({ "load": 4000.0 }));
+        let ed  = out.hoop.ed_required_ligament.expect("solvable");
+        let at  = ligament_stress(out.pressure, 0.25, ed, 0.75, 4.0);
+        assert!((at - 73.0).abs() < 1e-4, "7075 Sy at the requirement, got {at}");
+        assert_eq!(out.physics.ed_min_coupled, Some(ed));
+        assert_eq!(out.edge_distance.ed_min_strength, Some(ed));
+        assert!(out.candidates.iter().any(|c| c.name == "ligament" && c.margin == out.hoop.ligament_ms));
+    }
+
+    #[test]
+    fn edge_load_raises_stress_and_requirement() {
+        let free   = solve_with(json!({}));
+        let loaded = solve_with(json!({ "load": 4000.0 }));
+        let angled = solve_with(json!({ "load": 4000.0, "edgeLoadAngleDeg": 60.0 }));
+        assert!(loaded.hoop.ligament_sigma > angled.hoop.ligament_sigma);
+        assert!(angled.hoop.ligament_sigma > free.hoop.ligament_sigma);
+        assert!(loaded.physics.ed_min_coupled > free.physics.ed_min_coupled);
+        let expected = free.hoop.ligament_sigma + 2.0 / (2.0 * 0.75 * 0.75);
+        assert!((angled.hoop.ligament_sigma - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn short_edge_governs_and_warns() {
+        let out = solve_with(json!({ "edgeDist": 0.8, "load": 60000.0 }));
+        assert!(out.hoop.ligament_ms < 0.0);
+        assert_eq!(out.edge_distance.governing, "strength");
+        assert!(out.warning_codes.iter().any(|w| w.code == BushingWarningCode::EdgeDistanceStrengthFail));
+    }
+
+    #[test]
+    fn unsolvable_when_pressure_exceeds_allowable() {
+        let mat = crate::materials::lookup("Al_7075_T6").unwrap();
+        let mut input = input_with(json!({}));
+        input.load = None;
+        let lig = evaluate(&input, &mat, 0.5, 2.0 * mat.sy_ksi);
+        assert!(lig.ed_required.is_none());
+        assert!(lig.ms < 0.0);
+    }
+
+    #[test]
+    fn unsolvable_requirement_is_an_error_not_a_distance() {
+        let out = solve_with(json!({ "interference": 0.01 }));
+        assert!(out.pressure > 73.0 && out.hoop.ligament_ms < 0.0);
+        assert_eq!((out.physics.ed_min_coupled, out.edge_distance.ed_min_strength), (None, None));
+        assert_eq!(out.edge_distance.governing, "strength");
+        assert!(!out.candidates.iter().any(|c| c.name == "edge_distance_strength"));
+        let w = out.warning_codes.iter().find(|w| w.code == BushingWarningCode::EdgeDistanceStrengthFail).unwrap();
+        assert_eq!(w.severity, WarningSeverity::Error);
+    }
+}
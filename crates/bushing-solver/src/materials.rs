@@ -1,25 +1,205 @@
-/// Embedded material database.
-/// Ported from src/lib/core/bushing/materials.ts
-use crate::types::MaterialProps;
+/// Material library: the built-in data file, user libraries loaded from JSON or
+/// CSV, allowable-basis selection and temperature-dependent properties.
+/// Built-in values ported from src/lib/core/bushing/materials.ts
+use std::{path::Path, sync::OnceLock};
 
-/// Look up a material by ID string. Returns None if not in database.
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{error::BushingError, types::*};
+
+/// Built-in materials shipped with the solver (room-temperature values).
+const BUILTIN_MATERIALS: &str = include_str!("../data/materials.json");
+
+/// CSV columns holding text or temperature curves; every other column is numeric.
+const TEXT_COLUMNS: [&str; 3] = ["id", "name", "wear_family"];
+const CURVE_COLUMNS: [&str; 3] = ["e_curve", "sy_curve", "alpha_curve"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaterialLibrary {
+    pub version: u32,
+    pub materials: Vec<MaterialProps>,
+}
+
+impl MaterialLibrary {
+    pub fn from_json(text: &str) -> Result<Self, BushingError> {
+        let lib: Self = serde_json::from_str(text)?;
+        lib.materials.iter().try_for_each(validate)?;
+        Ok(lib)
+    }
+
+    /// Parse a header-row CSV. `a_*` / `b_*` columns (e.g. `a_sy_ksi`) fill
+    /// the basis allowables; curve columns take `temp:factor` pairs separated
+    /// by `;`. Blank cells are omitted; `#` lines are comments.
+    pub fn from_csv(text: &str) -> Result<Self, BushingError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty() && !l.trim_start().starts_with('#'));
+        let Some((_, header)) = lines.next() else {
+            return Ok(Self { version: 1, materials: vec![] });
+        };
+        let columns: Vec<&str> = header.split(',').map(str::trim).collect();
+        let materials = lines
+            .map(|(n, line)| {
+                let row = csv_row(&columns, line).map_err(|e| BushingError::InvalidInput(format!("line {}: {e}", n + 1)))?;
+                let m: MaterialProps = serde_json::from_value(row)
+                    .map_err(|e| BushingError::InvalidInput(format!("line {}: {e}", n + 1)))?;
+                validate(&m)?;
+                Ok(m)
+            })
+            .collect::<Result<_, BushingError>>()?;
+        Ok(Self { version: 1, materials })
+    }
+
+    /// Load a library file; `.csv` files are read as CSV, anything else as JSON.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BushingError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| BushingError::InvalidInput(format!("reading material file {}: {e}", path.display())))?;
+        if path.extension().is_some_and(|e| e.eq_ignore_ascii_case("csv")) {
+            Self::from_csv(&text)
+        } else {
+            Self::from_json(&text)
+        }
+    }
+
+    /// The materials shipped with the solver.
+    pub fn builtin() -> &'static MaterialLibrary {
+        static LIBRARY: OnceLock<MaterialLibrary> = OnceLock::new();
+        LIBRARY.get_or_init(|| MaterialLibrary::from_json(BUILTIN_MATERIALS).expect("built-in materials parse"))
+    }
+
+    /// Layer `other` on top of this library; a material with an existing `id` replaces it.
+    pub fn merge(&mut self, other: MaterialLibrary) {
+        for m in other.materials {
+            match self.materials.iter_mut().find(|x| x.id == m.id) {
+                Some(existing) => *existing = m,
+                None           => self.materials.push(m),
+            }
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&MaterialProps> {
+        self.materials.iter().find(|m| m.id == id)
+    }
+}
+
+fn csv_row(columns: &[&str], line: &str) -> Result<Value, String> {
+    let mut row = Map::new();
+    for (col, cell) in columns.iter().zip(line.split(',').map(str::trim)) {
+        if cell.is_empty() {
+            continue;
+        }
+        let value = if TEXT_COLUMNS.contains(col) {
+            Value::from(cell)
+        } else if CURVE_COLUMNS.contains(col) {
+            let points = cell
+                .split(';')
+                .map(|p| {
+                    let (t, k) = p.split_once(':').ok_or_else(|| format!("{col}: expected temp:factor, got '{p}'"))?;
+                    Ok(Value::from(vec![number(col, t)?, number(col, k)?]))
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            Value::from(points)
+        } else {
+            Value::from(number(col, cell)?)
+        };
+        match col.split_once('_').filter(|(p, _)| matches!(*p, "a" | "b")) {
+            Some((basis, key)) => {
+                let entry = row.entry(format!("{basis}_basis")).or_insert_with(|| Value::Object(Map::new()));
+                entry[key] = value;
+            }
+            None => {
+                row.insert(col.to_string(), value);
+            }
+        }
+    }
+    Ok(Value::Object(row))
+}
+
+fn number(col: &str, cell: &str) -> Result<f64, String> {
+    cell.trim().parse().map_err(|_| format!("{col}: '{cell}' is not a number"))
+}
+
+/// Reject physically meaningless entries before they reach the solver.
+pub fn validate(m: &MaterialProps) -> Result<(), BushingError> {
+    let bad = |what: &str| Err(BushingError::InvalidInput(format!("material {}: {what}", m.id)));
+    if m.id.trim().is_empty() {
+        return Err(BushingError::InvalidInput("material id must not be empty".into()));
+    }
+    if m.e_ksi <= 0.0 || m.sy_ksi <= 0.0 || m.fbru_ksi <= 0.0 || m.fsu_ksi <= 0.0 {
+        return bad("moduli and strengths must be > 0");
+    }
+    if !(0.0..0.5).contains(&m.nu) {
+        return bad("nu must be in [0, 0.5)");
+    }
+    for (name, curve) in [("e_curve", &m.e_curve), ("sy_curve", &m.sy_curve), ("alpha_curve", &m.alpha_curve)] {
+        if curve.windows(2).any(|w| w[1][0] <= w[0][0]) || curve.iter().any(|p| p[1] <= 0.0) {
+            return bad(&format!("{name} needs ascending temperatures and positive factors"));
+        }
+    }
+    Ok(())
+}
+
+/// Look up a built-in material by ID string. Returns None if not in the library.
 pub fn lookup(id: &str) -> Option<MaterialProps> {
-    DATABASE.iter().find(|m| m.id == id).cloned()
-}
-
-/// All known materials.  Values: E_ksi, Sy_ksi, Fbru_ksi, Fsu_ksi, Ftu_ksi?, nu, alpha_uF
-pub static DATABASE: &[MaterialProps] = &[
-    MaterialProps { id: "Al_7075_T6",    name: "Al 7075-T6",          e_ksi: 10_400.0, sy_ksi: 73.0,  fbru_ksi: 160.0, fsu_ksi: 48.0,  ftu_ksi: Some(83.0), nu: 0.33, alpha_u_f: 12.9 },
-    MaterialProps { id: "Al_2024_T3",    name: "Al 2024-T3",          e_ksi: 10_500.0, sy_ksi: 50.0,  fbru_ksi: 130.0, fsu_ksi: 37.0,  ftu_ksi: Some(70.0), nu: 0.33, alpha_u_f: 12.9 },
-    MaterialProps { id: "Al_6061_T6",    name: "Al 6061-T6",          e_ksi: 10_000.0, sy_ksi: 40.0,  fbru_ksi: 87.0,  fsu_ksi: 30.0,  ftu_ksi: Some(45.0), nu: 0.33, alpha_u_f: 13.1 },
-    MaterialProps { id: "SS_17_4_PH",    name: "SS 17-4 PH (H900)",   e_ksi: 28_500.0, sy_ksi: 170.0, fbru_ksi: 290.0, fsu_ksi: 102.0, ftu_ksi: Some(190.0), nu: 0.272, alpha_u_f: 6.0 },
-    MaterialProps { id: "SS_300",        name: "SS 304/316 Annealed",  e_ksi: 28_000.0, sy_ksi: 30.0,  fbru_ksi: 85.0,  fsu_ksi: 43.0,  ftu_ksi: Some(85.0), nu: 0.29,  alpha_u_f: 9.6 },
-    MaterialProps { id: "Ti_6Al_4V",     name: "Ti-6Al-4V",            e_ksi: 16_000.0, sy_ksi: 128.0, fbru_ksi: 250.0, fsu_ksi: 82.0,  ftu_ksi: Some(138.0), nu: 0.342, alpha_u_f: 5.2 },
-    MaterialProps { id: "Steel_4130",    name: "Steel 4130 (N)",       e_ksi: 29_000.0, sy_ksi: 70.0,  fbru_ksi: 130.0, fsu_ksi: 65.0,  ftu_ksi: Some(97.0), nu: 0.29, alpha_u_f: 6.3 },
-    MaterialProps { id: "Steel_4340",    name: "Steel 4340 (180 ksi)", e_ksi: 29_000.0, sy_ksi: 170.0, fbru_ksi: 310.0, fsu_ksi: 99.0,  ftu_ksi: Some(185.0), nu: 0.29, alpha_u_f: 6.3 },
-    MaterialProps { id: "Inconel_718",   name: "Inconel 718",          e_ksi: 29_900.0, sy_ksi: 150.0, fbru_ksi: 290.0, fsu_ksi: 103.0, ftu_ksi: Some(185.0), nu: 0.29, alpha_u_f: 7.2 },
-    MaterialProps { id: "Bronze_C93200", name: "Bronze C93200",        e_ksi: 14_000.0, sy_ksi: 20.0,  fbru_ksi: 65.0,  fsu_ksi: 27.0,  ftu_ksi: Some(35.0), nu: 0.34, alpha_u_f: 10.0 },
-];
+    MaterialLibrary::builtin().get(id).cloned()
+}
+
+/// Resolve `id` from the input's inline materials, then `library`.
+pub fn resolve(input: &BushingInput, library: &MaterialLibrary, id: &str) -> Option<(MaterialProps, &'static str)> {
+    match input.custom_materials.iter().find(|m| m.id == id) {
+        Some(m) => Some((m.clone(), "inline")),
+        None    => library.get(id).map(|m| (m.clone(), "library")),
+    }
+}
+
+/// Apply the allowables of `basis`; the flag is false when the material does
+/// not publish that basis and its base values are kept.
+pub fn with_basis(m: &MaterialProps, basis: MaterialBasis) -> (MaterialProps, bool) {
+    let published = match basis {
+        MaterialBasis::A => m.a_basis.as_ref(),
+        MaterialBasis::B => m.b_basis.as_ref(),
+        MaterialBasis::S => return (m.clone(), true),
+    };
+    let Some(a) = published else {
+        return (m.clone(), false);
+    };
+    let mut out = m.clone();
+    out.sy_ksi   = a.sy_ksi.unwrap_or(m.sy_ksi);
+    out.ftu_ksi  = a.ftu_ksi.or(m.ftu_ksi);
+    out.fbru_ksi = a.fbru_ksi.unwrap_or(m.fbru_ksi);
+    out.fsu_ksi  = a.fsu_ksi.unwrap_or(m.fsu_ksi);
+    (out, true)
+}
+
+/// Multiplier from `curve` at `temp_f`; 1 without a curve.
+pub fn curve_factor(curve: &[[f64; 2]], temp_f: f64) -> f64 {
+    let (Some(first), Some(last)) = (curve.first(), curve.last()) else {
+        return 1.0;
+    };
+    if temp_f <= first[0] {
+        return first[1];
+    }
+    if temp_f >= last[0] {
+        return last[1];
+    }
+    curve
+        .windows(2)
+        .find(|w| temp_f <= w[1][0])
+        .map(|w| w[0][1] + (w[1][1] - w[0][1]) * (temp_f - w[0][0]) / (w[1][0] - w[0][0]))
+        .unwrap_or(last[1])
+}
+
+/// Properties at a uniform temperature (°F) from the E, Sy and alpha curves.
+pub fn at_temperature(m: &MaterialProps, temp_f: f64) -> MaterialProps {
+    let mut out = m.clone();
+    out.e_ksi     *= curve_factor(&m.e_curve, temp_f);
+    out.sy_ksi    *= curve_factor(&m.sy_curve, temp_f);
+    out.alpha_u_f *= curve_factor(&m.alpha_curve, temp_f);
+    out
+}
 
 #[cfg(test)]
 mod tests {
@@ -35,4 +215,59 @@ mod tests {
     fn lookup_missing() {
         assert!(lookup("NotAMaterial").is_none());
     }
+
+    const CSV: &str = "\
+# shop library
+id,name,e_ksi,sy_ksi,fbru_ksi,fsu_ksi,ftu_ksi,nu,alpha_u_f,density_lb_in3,cp_btu_lb_f,a_sy_ksi,b_sy_ksi,sy_curve
+Al_7050_T7451,Al 7050-T7451,10300,66,140,43,76,0.33,12.8,0.102,0.23,64,67,70:1;300:0.8
+Al_7075_T6,Al 7075-T6 (shop),10400,70,160,48,,0.33,12.9,0.101,0.229,,,
+";
+
+    #[test]
+    fn csv_library_merges_over_builtins() {
+        let loaded = MaterialLibrary::from_csv(CSV).unwrap();
+        assert_eq!(loaded.materials.len(), 2);
+        let m = &loaded.materials[0];
+        assert_eq!(m.a_basis.as_ref().unwrap().sy_ksi, Some(64.0));
+        assert_eq!(m.sy_curve, vec![[70.0, 1.0], [300.0, 0.8]]);
+        assert_eq!(loaded.materials[1].ftu_ksi, None);
+
+        let mut lib = MaterialLibrary::builtin().clone();
+        let n = lib.materials.len();
+        lib.merge(loaded);
+        assert_eq!(lib.materials.len(), n + 1);
+        assert_eq!(lib.get("Al_7075_T6").unwrap().sy_ksi, 70.0);
+        assert!(MaterialLibrary::from_csv("id,e_ksi\nX,abc\n").is_err());
+    }
+
+    #[test]
+    fn basis_and_temperature_derating() {
+        let m = MaterialLibrary::from_csv(CSV).unwrap().materials.remove(0);
+        assert_eq!(with_basis(&m, MaterialBasis::A).0.sy_ksi, 64.0);
+        assert_eq!(with_basis(&m, MaterialBasis::B).0.fbru_ksi, 140.0);
+        let builtin = lookup("Al_7075_T6").unwrap();
+        let (kept, applied) = with_basis(&builtin, MaterialBasis::A);
+        assert!(!applied && kept.sy_ksi == builtin.sy_ksi);
+        assert!((at_temperature(&m, 185.0).sy_ksi - 66.0 * 0.9).abs() < 1e-12);
+        assert_eq!(at_temperature(&m, 500.0).sy_ksi, 66.0 * 0.8);
+        assert_eq!(at_temperature(&m, -65.0).sy_ksi, 66.0);
+        assert_eq!(at_temperature(&m, 300.0).e_ksi, 10_300.0);
+    }
+
+    #[test]
+    fn invalid_material_rejected() {
+        let mut m = lookup("SS_17_4_PH").unwrap();
+        m.sy_curve = vec![[300.0, 0.9], [70.0, 1.0]];
+        assert!(validate(&m).is_err());
+    }
+
+    #[test]
+    fn unpublished_basis_is_warned() {
+        let solve = |basis: &str| crate::test_support::solve_with(serde_json::json!({ "materialBasis": basis }));
+        let out = solve("a");
+        assert_eq!(out.materials.housing.source, "library");
+        assert!(!out.materials.housing.basis_applied);
+        assert!(out.warning_codes.iter().any(|w| w.code == BushingWarningCode::MaterialBasisUnavailable));
+        assert!(!solve("s").warning_codes.iter().any(|w| w.code == BushingWarningCode::MaterialBasisUnavailable));
+    }
 }
@@ -1,22 +1,238 @@
 /// Tolerance, Lame stress, countersink geometry math.
 /// Ported from src/lib/core/bushing/solveMath.ts
-use crate::types::ToleranceRange;
+use crate::{
+    types::{BoreProcessCapability, CsMode, CsSolvedDim, ToleranceMode, ToleranceRange},
+    units::Show,
+};
+
+/// Comparison slack for tolerance containment checks (inches).
+pub const EPS: f64 = 1e-9;
+
+/// Smallest bore lower limit the stack will resolve to (inches).
+pub const BORE_FLOOR: f64 = 1e-6;
 
 /// Build a tolerance range from nominal ± tol or lower/upper limits.
 pub fn make_range(lower: f64, upper: f64) -> ToleranceRange {
-    let nominal   = (lower + upper) / 2.0;
-    let tol_plus  = upper - nominal;
-    let tol_minus = nominal - lower;
+    make_range_at(ToleranceMode::NominalTol, lower, upper, (lower + upper) / 2.0)
+}
+
+/// Build a tolerance range with an explicit nominal, clamped into `[lower, upper]`.
+/// Reversed limits are swapped.
+pub fn make_range_at(mode: ToleranceMode, lower: f64, upper: f64, nominal: f64) -> ToleranceRange {
+    let lo  = lower.min(upper);
+    let hi  = lower.max(upper);
+    let nom = nominal.max(lo).min(hi);
     ToleranceRange {
-        mode: crate::types::ToleranceMode::NominalTol,
-        lower,
-        upper,
-        nominal,
-        tol_plus,
-        tol_minus,
+        mode,
+        lower: lo,
+        upper: hi,
+        nominal: nom,
+        tol_plus: hi - nom,
+        tol_minus: nom - lo,
+    }
+}
+
+/// Raw tolerance inputs for one dimension, as entered (either mode).
+#[derive(Debug, Clone, Copy)]
+pub struct ToleranceSpec {
+    pub mode: ToleranceMode,
+    pub nominal: f64,
+    pub tol_plus: Option<f64>,
+    pub tol_minus: Option<f64>,
+    pub lower: Option<f64>,
+    pub upper: Option<f64>,
+}
+
+/// Resolve a [`ToleranceSpec`] into a range. Missing tolerances default to zero;
+/// `min_floor` bounds the lower limit (use `f64::NEG_INFINITY` for none).
+pub fn resolve_tolerance(spec: &ToleranceSpec, min_floor: f64) -> ToleranceRange {
+    match spec.mode {
+        ToleranceMode::Limits => {
+            let lower = spec.lower.unwrap_or(spec.nominal);
+            let upper = spec.upper.unwrap_or(spec.nominal.max(lower));
+            let lo = lower.min(upper).max(min_floor);
+            let hi = lower.max(upper).max(lo);
+            make_range_at(ToleranceMode::Limits, lo, hi, spec.nominal)
+        }
+        ToleranceMode::NominalTol => {
+            let plus  = spec.tol_plus.unwrap_or(0.0).max(0.0);
+            let minus = spec.tol_minus.unwrap_or(0.0).max(0.0);
+            let lo = (spec.nominal - minus).max(min_floor);
+            let hi = (spec.nominal + plus).max(lo);
+            make_range_at(ToleranceMode::NominalTol, lo, hi, spec.nominal)
+        }
     }
 }
 
+/// Outcome of fitting a bushing OD band between a bore band and a target interference band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OdFitStatus {
+    Ok,
+    Clamped,
+    Infeasible,
+}
+
+impl OdFitStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OdFitStatus::Ok         => "ok",
+            OdFitStatus::Clamped    => "clamped",
+            OdFitStatus::Infeasible => "infeasible",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OdFit {
+    pub status: OdFitStatus,
+    pub notes: Vec<String>,
+    pub od: ToleranceRange,
+    pub achieved_interference: ToleranceRange,
+}
+
+/// Derive the bushing OD band that keeps every bore/OD combination inside the
+/// target interference band. When the bore band is wider than the interference
+/// band no such OD exists; the OD collapses to its nominal and the achieved
+/// interference spans the full bore band.
+pub fn build_od_tolerance(bore: &ToleranceRange, target: &ToleranceRange) -> OdFit {
+    let required_lower  = bore.upper + target.lower;
+    let required_upper  = bore.lower + target.upper;
+    let desired_nominal = bore.nominal + target.nominal;
+
+    if required_lower <= required_upper + EPS {
+        let hi      = required_upper.max(required_lower);
+        let nominal = desired_nominal.max(required_lower).min(hi);
+        let od      = make_range_at(ToleranceMode::Limits, required_lower, hi, nominal);
+        let achieved = make_range_at(
+            ToleranceMode::Limits,
+            od.lower - bore.upper,
+            od.upper - bore.lower,
+            od.nominal - bore.nominal,
+        );
+        let clamped = (nominal - desired_nominal).abs() > EPS;
+        let notes = if clamped {
+            vec!["OD nominal was clamped to keep fit inside the requested interference tolerance window.".into()]
+        } else {
+            vec![]
+        };
+        return OdFit {
+            status: if clamped { OdFitStatus::Clamped } else { OdFitStatus::Ok },
+            notes,
+            od,
+            achieved_interference: achieved,
+        };
+    }
+
+    let od = make_range_at(ToleranceMode::Limits, desired_nominal, desired_nominal, desired_nominal);
+    let achieved = make_range_at(
+        ToleranceMode::Limits,
+        od.nominal - bore.upper,
+        od.nominal - bore.lower,
+        od.nominal - bore.nominal,
+    );
+    OdFit {
+        status: OdFitStatus::Infeasible,
+        notes: vec!["Bore tolerance width exceeds interference tolerance width; full-range containment is infeasible.".into()],
+        od,
+        achieved_interference: achieved,
+    }
+}
+
+/// Result of tightening the bore band so the target interference band can be contained.
+#[derive(Debug, Clone)]
+pub struct BoreEnforcement {
+    pub adjusted: ToleranceRange,
+    pub changed: bool,
+    pub capability_blocked: bool,
+    pub nominal_shift: f64,
+    pub note: Option<String>,
+}
+
+/// Tighten the bore band to the target interference width.
+///
+/// With the nominal preserved, the tightened band keeps the entered nominal and
+/// splits the width in the same plus/minus proportion as the original band.
+/// With a nominal shift allowed, the band is anchored at the original upper limit
+/// (the entered minimum clean-up size is never reduced) and the nominal moves up
+/// by at most `max_shift`. Notes quote widths through `show`.
+pub fn enforce_bore_band(
+    bore: &ToleranceRange,
+    target: &ToleranceRange,
+    capability: &BoreProcessCapability,
+    allow_shift: bool,
+    max_shift: Option<f64>,
+    show: &Show,
+) -> BoreEnforcement {
+    let bore_width   = (bore.upper - bore.lower).max(0.0);
+    let target_width = (target.upper - target.lower).max(0.0);
+    let unchanged = |capability_blocked, note| BoreEnforcement {
+        adjusted: bore.clone(),
+        changed: false,
+        capability_blocked,
+        nominal_shift: 0.0,
+        note,
+    };
+    if bore_width <= target_width + EPS {
+        return unchanged(false, None);
+    }
+    if let Some(floor) = capability.min_achievable_tol_width.filter(|w| *w > target_width + EPS) {
+        return unchanged(
+            true,
+            Some(format!(
+                "Strict interference enforcement is blocked by bore process capability floor ({} > target width {}).",
+                show.length(floor, 4),
+                show.length(target_width, 4),
+            )),
+        );
+    }
+
+    let minus_share = if bore_width > EPS { bore.tol_minus / bore_width } else { 0.5 };
+    let held_lower  = (bore.nominal - minus_share * target_width)
+        .max(bore.lower)
+        .min(bore.upper - target_width);
+
+    if allow_shift {
+        let anchored_lower = bore.upper - target_width;
+        let limit          = max_shift.unwrap_or(f64::INFINITY).max(0.0);
+        let shift          = (anchored_lower - held_lower).max(0.0).min(limit);
+        let lower          = (held_lower + shift).max(BORE_FLOOR);
+        let upper          = lower + target_width;
+        let nominal        = bore.nominal + shift;
+        return BoreEnforcement {
+            adjusted: make_range_at(ToleranceMode::Limits, lower, upper, nominal),
+            changed: true,
+            capability_blocked: false,
+            nominal_shift: shift,
+            note: Some(format!(
+                "Bore tolerance was tightened to {} and its nominal shifted upward by {} toward the upper limit.",
+                show.length(target_width, 4),
+                show.length(shift, 4),
+            )),
+        };
+    }
+
+    let lower = held_lower.max(BORE_FLOOR);
+    BoreEnforcement {
+        adjusted: make_range_at(ToleranceMode::Limits, lower, lower + target_width, bore.nominal),
+        changed: true,
+        capability_blocked: false,
+        nominal_shift: 0.0,
+        note: Some(format!(
+            "Bore tolerance was tightened to {} about the entered nominal inside the original limits.",
+            show.length(target_width, 4),
+        )),
+    }
+}
+
+/// Amount by which the achieved interference band falls outside the target band.
+/// Returns `(lower_violation, upper_violation)`, both non-negative.
+pub fn containment_violations(achieved: &ToleranceRange, target: &ToleranceRange) -> (f64, f64) {
+    (
+        (target.lower - achieved.lower).max(0.0),
+        (achieved.upper - target.upper).max(0.0),
+    )
+}
+
 /// Hoop (circumferential) stress in a thick-walled cylinder at radius r,
 /// under internal pressure p (Lame's equations, sign convention: tension +).
 pub fn lame_hoop_stress(inner_r: f64, outer_r: f64, pressure: f64, r: f64) -> f64 {
@@ -45,8 +261,59 @@ pub fn build_lame_field(inner_r: f64, outer_r: f64, pressure: f64, samples: usiz
         .collect()
 }
 
+/// Radial and hoop stress at radius r in a thick-walled cylinder loaded by
+/// external pressure only (the bushing under the fit), tension positive.
+pub fn lame_external_stresses(inner_r: f64, outer_r: f64, pressure: f64, r: f64) -> (f64, f64) {
+    let ri2 = inner_r * inner_r;
+    let ro2 = outer_r * outer_r;
+    let k   = pressure * ro2 / (ro2 - ri2);
+    (-k * (1.0 - ri2 / (r * r)), -k * (1.0 + ri2 / (r * r)))
+}
+
+/// Sample the external-pressure Lame field at N evenly-spaced radii.
+pub fn build_lame_field_external(inner_r: f64, outer_r: f64, pressure: f64, samples: usize) -> Vec<(f64, f64, f64)> {
+    (0..samples)
+        .map(|i| {
+            let t = i as f64 / (samples - 1) as f64;
+            let r = inner_r + t * (outer_r - inner_r);
+            let (sigma_r, sigma_theta) = lame_external_stresses(inner_r, outer_r, pressure, r);
+            (r, sigma_r, sigma_theta)
+        })
+        .collect()
+}
+
+/// Von Mises equivalent of three principal stresses.
+pub fn von_mises(s1: f64, s2: f64, s3: f64) -> f64 {
+    (0.5 * ((s1 - s2).powi(2) + (s2 - s3).powi(2) + (s3 - s1).powi(2))).sqrt()
+}
+
+/// Diametral compliance terms (in/ksi) of the bushing and housing, d/E·(Lamé
+/// factor ∓ ν). `housing_factor` (psi) scales the housing term for
+/// non-circular housings.
+#[allow(clippy::too_many_arguments)]
+pub fn lame_compliance_terms(
+    bore_dia: f64,
+    id_bushing: f64,
+    od_housing: f64,
+    e_bushing_ksi: f64,
+    nu_bushing: f64,
+    e_housing_ksi: f64,
+    nu_housing: f64,
+    housing_factor: f64,
+) -> (f64, f64) {
+    let d    = bore_dia;
+    let id_b = id_bushing;
+    let od_h = od_housing;
+
+    let term_b = d / e_bushing_ksi * ((d * d + id_b * id_b) / (d * d - id_b * id_b) - nu_bushing);
+    let term_h = housing_factor * d / e_housing_ksi * ((od_h * od_h + d * d) / (od_h * od_h - d * d) + nu_housing);
+    (term_b, term_h)
+}
+
 /// Compute contact pressure between bushing OD and housing bore from interference fit.
-/// Uses the composite Lame formulation (Shigley / Boresi).
+/// Uses the composite Lame formulation (Shigley / Boresi) for a circular housing;
+/// other housings scale the housing term of [`lame_compliance_terms`].
+#[allow(clippy::too_many_arguments)]
 pub fn lame_contact_pressure(
     delta_total: f64,
     bore_dia: f64,
@@ -60,35 +327,59 @@ pub fn lame_contact_pressure(
     if delta_total <= 0.0 {
         return 0.0;
     }
-    let d    = bore_dia;
-    let id_b = id_bushing;
-    let od_h = od_housing;
+    let (term_b, term_h) =
+        lame_compliance_terms(bore_dia, id_bushing, od_housing, e_bushing_ksi, nu_bushing, e_housing_ksi, nu_housing, 1.0);
+    delta_total / (term_b + term_h)
+}
 
-    let term_b = (d * d + id_b * id_b) / (d * d - id_b * id_b) - nu_bushing;
-    let term_h = (od_h * od_h + d * d) / (od_h * od_h - d * d) + nu_housing;
-    let w_eff  = term_b / e_bushing_ksi + term_h / e_housing_ksi;
+/// Countersink geometry solver: resolve the third of {dia, depth, angle} from
+/// the two fixed by `mode`, measured from a cylinder of diameter `base_dia`.
+/// Ported from solveMath.ts::solveCountersink.
+pub fn solve_countersink(mode: CsMode, dia: f64, depth: f64, angle_deg: f64, base_dia: f64) -> CsSolvedDim {
+    let base  = if base_dia.is_finite() { base_dia.max(0.0) } else { 0.0 };
+    let dia   = if dia.is_finite() { dia.max(0.0) } else { 0.0 };
+    let depth = if depth.is_finite() { depth.max(0.0) } else { 0.0 };
+    let angle = if angle_deg.is_finite() { angle_deg.clamp(1e-3, 179.999) } else { 100.0 };
+    let tan_half = (angle / 2.0).to_radians().tan();
+    match mode {
+        CsMode::DepthAngle => CsSolvedDim { dia: (base + 2.0 * depth * tan_half).max(base), depth, angle_deg: angle },
+        CsMode::DiaAngle   => CsSolvedDim {
+            dia,
+            depth: if tan_half > 1e-9 { ((dia - base) / (2.0 * tan_half)).max(0.0) } else { 0.0 },
+            angle_deg: angle,
+        },
+        CsMode::DiaDepth   => {
+            let solved = if depth > 1e-9 { 2.0 * ((dia - base).max(0.0) / (2.0 * depth)).atan() } else { 0.0 };
+            CsSolvedDim { dia, depth, angle_deg: solved.to_degrees().clamp(0.0, 179.999) }
+        }
+    }
+}
 
-    delta_total / (d * w_eff)
+/// Countersink diameter band. In depth/angle mode the diameter follows the
+/// depth band and the `base` band it is cut from; otherwise it is fixed.
+pub fn cs_dia_tolerance(mode: CsMode, solved: &CsSolvedDim, base: &ToleranceRange, depth: &ToleranceRange) -> ToleranceRange {
+    if mode != CsMode::DepthAngle {
+        return make_range_at(ToleranceMode::NominalTol, solved.dia, solved.dia, solved.dia);
+    }
+    let tan_half = (solved.angle_deg / 2.0).to_radians().tan();
+    let lower = base.lower + 2.0 * depth.lower.max(0.0) * tan_half;
+    let upper = base.upper + 2.0 * depth.upper.max(0.0) * tan_half;
+    make_range_at(base.mode, lower, upper, solved.dia)
 }
 
-/// Countersink geometry solver: given two of {dia, depth, angle}, compute the third.
-/// Returns (dia, depth) after resolution.
-pub fn solve_countersink(
-    dia: Option<f64>,
-    depth: Option<f64>,
-    angle_deg: f64,
-) -> Option<(f64, f64)> {
-    let half_angle = (angle_deg / 2.0).to_radians();
-    let tan_half   = half_angle.tan();
-    if tan_half.abs() < 1e-12 {
-        return None;
+/// Countersink depth band. Depth-driven modes use the entered depth band; in
+/// dia/angle mode the depth follows the `base` band under the fixed diameter.
+pub fn cs_depth_tolerance(mode: CsMode, solved: &CsSolvedDim, base: &ToleranceRange, depth: &ToleranceRange) -> ToleranceRange {
+    if mode != CsMode::DiaAngle {
+        return depth.clone();
     }
-    match (dia, depth) {
-        (Some(d), Some(h)) => Some((d, h)),
-        (Some(d), None)    => Some((d, (d / 2.0) / tan_half)),
-        (None, Some(h))    => Some((h * tan_half * 2.0, h)),
-        (None, None)       => None,
+    let tan_half = (solved.angle_deg / 2.0).to_radians().tan();
+    if !tan_half.is_finite() || tan_half.abs() < 1e-12 {
+        return make_range_at(ToleranceMode::NominalTol, solved.depth, solved.depth, solved.depth);
     }
+    let lower = ((solved.dia - base.upper) / (2.0 * tan_half)).max(0.0);
+    let upper = ((solved.dia - base.lower) / (2.0 * tan_half)).max(0.0);
+    make_range_at(base.mode, lower, upper, solved.depth)
 }
 
 #[cfg(test)]
@@ -105,9 +396,20 @@ mod tests {
         assert!((sigma - 16.666_666).abs() < 1e-3, "got {sigma}");
     }
 
+    #[test]
+    fn contact_pressure_matches_closed_form_for_one_material() {
+        // Same E and ν: p = E·(δ/2)·(b²−a²)(c²−b²) / (2b³(c²−a²)) for radii a < b < c
+        let (a, b, c, e, delta) = (0.125, 0.25, 0.75, 10_300.0, 0.0015);
+        let p = lame_contact_pressure(delta, 2.0 * b, 2.0 * a, 2.0 * c, e, 0.33, e, 0.33);
+        let expected = e * (delta / 2.0) * (b * b - a * a) * (c * c - b * b) / (2.0 * b.powi(3) * (c * c - a * a));
+        assert!((p - expected).abs() < 1e-9 * expected, "p={p} expected={expected}");
+        assert_eq!(lame_contact_pressure(0.0, 2.0 * b, 2.0 * a, 2.0 * c, e, 0.33, e, 0.33), 0.0);
+    }
+
     #[test]
     fn countersink_depth_from_dia() {
-        let (d, h) = solve_countersink(Some(0.5), None, 100.0).unwrap();
+        let cs = solve_countersink(CsMode::DiaAngle, 0.5, 0.0, 100.0, 0.0);
+        let (d, h) = (cs.dia, cs.depth);
         assert!((d - 0.5).abs() < 1e-9);
         let expected_h = (0.5 / 2.0) / (50_f64.to_radians().tan());
         assert!((h - expected_h).abs() < 1e-6, "h={h} expected={expected_h}");
@@ -0,0 +1,257 @@
+/// Measured-part (as-built) mode: substitute the measured bore, bushing OD / ID,
+/// edge distance and width for their nominal values, and derive bore roundness
+/// and taper from station readings.
+/// Override and note wording ported from solveEngine.ts (measured part section).
+use crate::{math::EPS, process, types::*, units::Show};
+
+const UM_PER_INCH: f64 = 25_400.0;
+
+/// Input with the measurements applied, plus the basis and summary to report.
+pub struct Applied {
+    pub input: BushingInput,
+    pub active: bool,
+    pub basis: InputBasis,
+    pub summary: MeasuredPartSummary,
+}
+
+fn mean(xs: &[f64]) -> Option<f64> {
+    (!xs.is_empty()).then(|| xs.iter().sum::<f64>() / xs.len() as f64)
+}
+
+/// Reported size: `actual`, else the mean of the readings.
+fn size(d: &MeasuredDimension) -> Option<f64> {
+    d.actual.or_else(|| mean(&d.readings.iter().map(|r| r.dia).collect::<Vec<_>>()))
+}
+
+/// Size band: entered tolerances about the size, else the reading extremes.
+fn band(d: &MeasuredDimension, size: f64) -> (f64, f64) {
+    if d.tol_plus.is_some() || d.tol_minus.is_some() {
+        return (size - d.tol_minus.unwrap_or(0.0), size + d.tol_plus.unwrap_or(0.0));
+    }
+    d.readings
+        .iter()
+        .fold((size, size), |(lo, hi), r| (lo.min(r.dia), hi.max(r.dia)))
+}
+
+/// Readings grouped by axial station (unstationed readings share one group).
+fn stations(d: &MeasuredDimension) -> Vec<(f64, Vec<f64>)> {
+    let mut groups: Vec<(f64, Vec<f64>)> = vec![];
+    for r in &d.readings {
+        let z = r.station.unwrap_or(0.0);
+        match groups.iter_mut().find(|(s, _)| (s - z).abs() < EPS) {
+            Some((_, g)) => g.push(r.dia),
+            None         => groups.push((z, vec![r.dia])),
+        }
+    }
+    groups.sort_by(|a, b| a.0.total_cmp(&b.0));
+    groups
+}
+
+/// Largest max − min spread at any station with two or more orientations (µm).
+pub fn roundness_um(d: &MeasuredDimension) -> Option<f64> {
+    stations(d)
+        .iter()
+        .filter(|(_, g)| g.len() >= 2)
+        .map(|(_, g)| {
+            let hi = g.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let lo = g.iter().copied().fold(f64::INFINITY, f64::min);
+            (hi - lo) * UM_PER_INCH
+        })
+        .reduce(f64::max)
+}
+
+/// Mean-diameter change per inch between the first and last stations.
+pub fn taper(d: &MeasuredDimension) -> Option<f64> {
+    let st = stations(d);
+    let (first, last) = (st.first()?, st.last()?);
+    let span = last.0 - first.0;
+    (span > EPS).then(|| (mean(&last.1).unwrap_or(0.0) - mean(&first.1).unwrap_or(0.0)) / span)
+}
+
+fn basis(measured: bool) -> String {
+    if measured { "measured" } else { "nominal" }.into()
+}
+
+/// Apply the measured part to a copy of `input`. Inactive or absent
+/// measurements return the input unchanged with a nominal basis.
+pub fn apply(input: &BushingInput) -> Applied {
+    let mut out = input.clone();
+    let Some(mp) = &input.measured_part else {
+        return Applied {
+            input: out,
+            active: false,
+            basis: InputBasis { bore: basis(false), id: basis(false), edge_dist: basis(false), housing_width: basis(false) },
+            summary: MeasuredPartSummary { basis: basis(false), ..Default::default() },
+        };
+    };
+    let active = mp.enabled && mp.basis == "measured";
+    let mut overrides = vec![];
+    let mut notes: Vec<String> = mp.notes.iter().cloned().collect();
+
+    let bore = mp.bore.as_ref().and_then(|d| Some((d, size(d)?))).filter(|_| active);
+    let od   = mp.od.as_ref().and_then(|d| Some((d, size(d)?))).filter(|_| active);
+    let id   = mp.id.as_ref().and_then(size).filter(|_| active);
+    let edge = mp.edge_dist.filter(|_| active);
+    let wide = mp.housing_width.filter(|_| active);
+
+    // Design bushing OD, held when only the bore is measured
+    let show      = Show::new(input.units);
+    let design_od = input.bore_nominal.unwrap_or(input.bore_dia) + input.interference_nominal.unwrap_or(input.interference);
+
+    let (bore_lo, bore_hi) = match bore {
+        Some((d, b)) => {
+            let (lo, hi)      = band(d, b);
+            out.bore_dia      = b;
+            out.bore_nominal  = Some(b);
+            out.bore_tol_mode = ToleranceMode::Limits;
+            out.bore_lower    = Some(lo);
+            out.bore_upper    = Some(hi);
+            overrides.push(format!(
+                "Measured bore {} (nominal {}) applied to fit solve.",
+                show.length(b, 4),
+                show.length(input.bore_dia, 4)
+            ));
+            (lo, hi)
+        }
+        None => (out.bore_dia, out.bore_dia),
+    };
+    if bore.is_some() || od.is_some() {
+        let (od_size, od_lo, od_hi) = match od {
+            Some((d, o)) => {
+                let (lo, hi) = band(d, o);
+                overrides.push(format!("Measured bushing OD {} sets the as-built interference.", show.length(o, 4)));
+                (o, lo, hi)
+            }
+            None => {
+                notes.push(format!("Bushing OD not measured; design OD {} assumed against the measured bore.", show.length(design_od, 4)));
+                (design_od, design_od, design_od)
+            }
+        };
+        let delta = od_size - out.bore_dia;
+        out.interference          = delta;
+        out.interference_nominal  = Some(delta);
+        out.interference_tol_mode = ToleranceMode::Limits;
+        out.interference_lower    = Some(od_lo - bore_hi);
+        out.interference_upper    = Some(od_hi - bore_lo);
+    }
+    if let Some(i) = id {
+        out.id_bushing = i;
+        overrides.push("Measured ID applied to service and profile solve.".into());
+    }
+    if let Some(e) = edge {
+        out.edge_dist = e;
+        overrides.push("Measured edge distance applied to geometry checks.".into());
+    }
+    if let Some(w) = wide {
+        out.housing_width = w;
+        overrides.push("Measured surrounding width applied to finite-plate geometry.".into());
+    }
+
+    let bore_dim   = mp.bore.as_ref();
+    let derived_rd = bore_dim.and_then(roundness_um);
+    let roundness  = bore_dim.and_then(|d| d.roundness).or(derived_rd);
+    let bore_taper = bore_dim.and_then(taper);
+    for (label, dim) in [("bore", mp.bore.as_ref()), ("ID", mp.id.as_ref())] {
+        let Some(d) = dim else { continue };
+        if let Some(r) = d.roundness {
+            notes.push(format!("Measured {label} roundness recorded: {r:.0} um."));
+        }
+        if let Some(ra) = d.ra {
+            notes.push(format!("Measured {label} finish recorded: Ra {ra:.2} um."));
+        }
+    }
+    if let (None, Some(r)) = (bore_dim.and_then(|d| d.roundness), derived_rd) {
+        notes.push(format!("Bore roundness {r:.0} um derived from station readings."));
+    }
+    if let Some(t) = bore_taper {
+        notes.push(format!("Bore taper {t:+.5} {0}/{0} between end stations.", show.length_label()));
+    }
+    let target = process::route(input.process_route_id).roundness_target_um;
+    if let Some(r) = roundness.filter(|r| *r > target) {
+        notes.push(format!("Bore roundness {r:.0} um exceeds the {target:.0} um route target."));
+    }
+
+    Applied {
+        active,
+        basis: InputBasis {
+            bore: basis(bore.is_some()),
+            id: basis(id.is_some()),
+            edge_dist: basis(edge.is_some()),
+            housing_width: basis(wide.is_some()),
+        },
+        summary: MeasuredPartSummary {
+            applied: active && !overrides.is_empty(),
+            basis: basis(active),
+            overrides,
+            notes,
+            bore_roundness_um: roundness,
+            bore_taper,
+        },
+        input: out,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        solver,
+        test_support::{input_with, solve_with},
+    };
+    use serde_json::json;
+
+    fn readings() -> serde_json::Value {
+        json!([
+            { "dia": 0.5004, "station": 0.0,  "angleDeg": 0.0 },
+            { "dia": 0.5010, "station": 0.0,  "angleDeg": 90.0 },
+            { "dia": 0.5008, "station": 0.75, "angleDeg": 0.0 },
+            { "dia": 0.5010, "station": 0.75, "angleDeg": 90.0 },
+        ])
+    }
+
+    #[test]
+    fn nominal_when_absent_or_disabled() {
+        let out = solve_with(json!({}));
+        assert!(!out.measured_part_summary.applied);
+        assert_eq!(out.input_basis.bore, "nominal");
+        let off = solve_with(json!({ "measuredPart": { "enabled": false, "basis": "measured", "bore": { "actual": 0.502 } } }));
+        assert!(!off.measured_part_summary.applied);
+        assert_eq!(off.lame.bore_dia, 0.5);
+    }
+
+    #[test]
+    fn measured_bore_and_od_set_as_built_fit() {
+        let out = solve_with(json!({ "measuredPart": {
+            "enabled": true, "basis": "measured",
+            "bore": { "readings": readings() },
+            "od": { "actual": 0.5022 },
+            "edgeDist": 0.9,
+        } }));
+        let s = &out.measured_part_summary;
+        assert!(s.applied);
+        assert_eq!(out.input_basis.bore, "measured");
+        assert_eq!(out.input_basis.edge_dist, "measured");
+        assert_eq!(out.input_basis.id, "nominal");
+        assert!((out.lame.bore_dia - 0.5008).abs() < 1e-12);
+        assert!((out.lame.delta_user - 0.0014).abs() < 1e-9);
+        assert!((out.tolerance.bore.lower - 0.5004).abs() < 1e-12 && (out.tolerance.bore.upper - 0.5010).abs() < 1e-12);
+        assert_eq!(out.edge_distance.ed_actual, 0.9);
+        assert_eq!(s.overrides.len(), 3);
+        // 0.0006 in spread at the entry station; mean grows 0.0002 over 0.75 in
+        assert!((s.bore_roundness_um.unwrap() - 0.0006 * 25_400.0).abs() < 1e-6);
+        assert!((s.bore_taper.unwrap() - 0.0002 / 0.75).abs() < 1e-9);
+        assert!(s.notes.iter().any(|n| n.contains("exceeds the 12 um route target")));
+    }
+
+    #[test]
+    fn oversize_bore_with_design_od_loses_interference() {
+        let out = solve_with(json!({ "measuredPart": { "enabled": true, "basis": "measured", "bore": { "actual": 0.5010 } } }));
+        assert!((out.lame.delta_user - 0.0005).abs() < 1e-9);
+        assert!(out.measured_part_summary.notes.iter().any(|n| n.contains("design OD 0.5015")));
+    }
+
+    #[test]
+    fn invalid_as_built_geometry_is_rejected() {
+        let input = input_with(json!({ "measuredPart": { "enabled": true, "basis": "measured", "edgeDist": 0.2 } }));
+        assert!(matches!(solver::compute(&input), Err(crate::BushingError::Schema(_))));
+    }
+}
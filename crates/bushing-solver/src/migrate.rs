@@ -0,0 +1,140 @@
+/// Input document versions: older documents are upgraded step by step to
+/// `CURRENT_VERSION`, recording every key that was renamed or dropped.
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::error::BushingError;
+
+/// Version of the document shape `BushingInputRaw` reads. Documents without
+/// `schemaVersion` are version 1.
+pub const CURRENT_VERSION: u64 = 2;
+pub const VERSION_KEY: &str = "schemaVersion";
+
+/// One upgrade step; `STEPS[i]` takes a document from version `i + 1` to `i + 2`.
+type Step = fn(&mut Map<String, Value>, &mut Migration);
+
+const STEPS: [Step; (CURRENT_VERSION - 1) as usize] = [v1_legacy_aliases];
+
+/// Version 1 aliases and their current names (camelCase, snake_case).
+const V1_RENAMES: [(&str, [&str; 2]); 4] = [
+    ("bushID",   ["idBushing", "id_bushing"]),
+    ("t1",       ["assemblyHousingTemperature", "assembly_housing_temperature"]),
+    ("t2",       ["assemblyBushingTemperature", "assembly_bushing_temperature"]),
+    ("thetaDeg", ["edgeLoadAngleDeg", "edge_load_angle_deg"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationAction {
+    Renamed,
+    /// Superseded by a current key that was already present.
+    Dropped,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MigrationChange {
+    /// Version the step upgraded to.
+    pub version: u64,
+    /// JSON pointer to the key in the original document.
+    pub pointer: String,
+    pub action: MigrationAction,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MigrationReport {
+    pub from_version: u64,
+    pub to_version: u64,
+    pub changes: Vec<MigrationChange>,
+}
+
+/// Changes recorded by the step in progress.
+struct Migration {
+    version: u64,
+    changes: Vec<MigrationChange>,
+}
+
+impl Migration {
+    fn push(&mut self, key: &str, action: MigrationAction, message: String) {
+        self.changes.push(MigrationChange { version: self.version, pointer: format!("/{key}"), action, message });
+    }
+}
+
+fn v1_legacy_aliases(doc: &mut Map<String, Value>, m: &mut Migration) {
+    for (legacy, [current, snake]) in V1_RENAMES {
+        let Some(value) = doc.remove(legacy) else {
+            continue;
+        };
+        match doc.get(current).or_else(|| doc.get(snake)) {
+            None => {
+                doc.insert(current.into(), value);
+                m.push(legacy, MigrationAction::Renamed, format!("{legacy} renamed to {current}"));
+            }
+            Some(existing) if *existing == value => {
+                m.push(legacy, MigrationAction::Dropped, format!("{legacy} dropped; it duplicates {current}"));
+            }
+            Some(existing) => {
+                m.push(
+                    legacy,
+                    MigrationAction::Dropped,
+                    format!("{legacy} = {value} dropped; {current} = {existing} takes precedence"),
+                );
+            }
+        }
+    }
+}
+
+/// Upgrade `doc` to `CURRENT_VERSION`. The result carries `schemaVersion`.
+pub fn migrate(doc: Value) -> Result<(Value, MigrationReport), BushingError> {
+    let Value::Object(mut doc) = doc else {
+        return Err(BushingError::InvalidInput("input must be a JSON object".into()));
+    };
+    let from = match doc.get(VERSION_KEY) {
+        None    => 1,
+        Some(v) => v
+            .as_u64()
+            .filter(|n| *n >= 1)
+            .ok_or_else(|| BushingError::InvalidInput(format!("{VERSION_KEY} must be a positive whole number, got {v}")))?,
+    };
+    if from > CURRENT_VERSION {
+        return Err(BushingError::InvalidInput(format!(
+            "{VERSION_KEY} {from} is newer than the supported version {CURRENT_VERSION}"
+        )));
+    }
+    let mut changes = vec![];
+    for (i, step) in STEPS.iter().enumerate().skip(from as usize - 1) {
+        let mut m = Migration { version: i as u64 + 2, changes: vec![] };
+        step(&mut doc, &mut m);
+        changes.extend(m.changes);
+    }
+    doc.insert(VERSION_KEY.into(), Value::from(CURRENT_VERSION));
+    Ok((Value::Object(doc), MigrationReport { from_version: from, to_version: CURRENT_VERSION, changes }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn legacy_aliases_are_renamed_with_a_report() {
+        let (doc, report) = migrate(json!({ "bushID": 0.25, "t1": 250.0, "thetaDeg": 30.0, "edge_load_angle_deg": 45.0 })).unwrap();
+        assert_eq!(doc, json!({ "idBushing": 0.25, "assemblyHousingTemperature": 250.0, "edge_load_angle_deg": 45.0, "schemaVersion": 2 }));
+        assert_eq!((report.from_version, report.to_version), (1, 2));
+        let actions: Vec<_> = report.changes.iter().map(|c| (c.pointer.as_str(), c.action)).collect();
+        assert_eq!(
+            actions,
+            [("/bushID", MigrationAction::Renamed), ("/t1", MigrationAction::Renamed), ("/thetaDeg", MigrationAction::Dropped)]
+        );
+    }
+
+    #[test]
+    fn current_documents_pass_through_and_newer_ones_are_rejected() {
+        let doc = json!({ "schemaVersion": 2, "bushID": 0.25 });
+        let (out, report) = migrate(doc.clone()).unwrap();
+        assert_eq!(out, doc);
+        assert!(report.changes.is_empty());
+        assert!(matches!(migrate(json!({ "schemaVersion": 3 })), Err(BushingError::InvalidInput(_))));
+        assert!(matches!(migrate(json!({ "schemaVersion": "2" })), Err(BushingError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,234 @@
+/// Bushing process route library and install / removal process review.
+/// Ported from src/lib/core/bushing/processLibrary.ts and serviceAnalysis.ts (process section).
+use crate::{service::ServiceInputs, types::*, units::Show};
+
+/// Retained install force above which press capacity should be reviewed (lbf).
+pub const PRESS_REVIEW_FORCE: f64 = 3000.0;
+/// Install band applied to the thermal-assist route once assembly temperatures are entered.
+const ACTIVE_THERMAL_BAND: (f64, f64) = (0.9, 1.1);
+
+#[derive(Debug, Clone)]
+pub struct ProcessRoute {
+    pub id: BushingProcessRouteId,
+    pub label: &'static str,
+    pub tolerance_class: &'static str,
+    pub recommended_ra_um: f64,
+    pub roundness_target_um: f64,
+    pub finish_machining_required: bool,
+    pub thermal_assist_recommended: bool,
+    /// Removal force as a multiple of the retained install force.
+    pub removal_force_factor: f64,
+    /// Low / high multipliers on the nominal install force.
+    pub install_force_band: (f64, f64),
+    /// Diametral clean-up stock beyond the installed ID closure for finish machining (inches).
+    pub cleanup_stock: f64,
+    pub notes: &'static [&'static str],
+}
+
+pub static ROUTES: &[ProcessRoute] = &[
+    ProcessRoute {
+        id: BushingProcessRouteId::PressFitOnly,
+        label: "Press Fit Only",
+        tolerance_class: "reamed / controlled",
+        recommended_ra_um: 1.6,
+        roundness_target_um: 12.0,
+        finish_machining_required: false,
+        thermal_assist_recommended: false,
+        removal_force_factor: 1.1,
+        install_force_band: (0.85, 1.15),
+        cleanup_stock: 0.0,
+        notes: &["Use when the installed ID remains acceptable without post-install machining."],
+    },
+    ProcessRoute {
+        id: BushingProcessRouteId::PressFitFinishReam,
+        label: "Press Fit + Finish Ream",
+        tolerance_class: "finish reamed",
+        recommended_ra_um: 0.8,
+        roundness_target_um: 8.0,
+        finish_machining_required: true,
+        thermal_assist_recommended: false,
+        removal_force_factor: 1.15,
+        install_force_band: (0.85, 1.15),
+        cleanup_stock: 0.002,
+        notes: &["Use when press-fit closure must be recovered with final ID machining after installation."],
+    },
+    ProcessRoute {
+        id: BushingProcessRouteId::LineReamRepair,
+        label: "Line Ream Repair",
+        tolerance_class: "repair / line ream",
+        recommended_ra_um: 1.6,
+        roundness_target_um: 15.0,
+        finish_machining_required: true,
+        thermal_assist_recommended: false,
+        removal_force_factor: 1.2,
+        install_force_band: (0.9, 1.2),
+        cleanup_stock: 0.004,
+        notes: &["Use for oversize or misalignment repair workflows with final line-ream alignment control."],
+    },
+    ProcessRoute {
+        id: BushingProcessRouteId::ThermalAssistInstall,
+        label: "Thermal Assist Install",
+        tolerance_class: "controlled with thermal assist",
+        recommended_ra_um: 1.6,
+        roundness_target_um: 10.0,
+        finish_machining_required: false,
+        thermal_assist_recommended: true,
+        removal_force_factor: 1.05,
+        install_force_band: (0.65, 0.95),
+        cleanup_stock: 0.0,
+        notes: &["Use when press force is high enough that chill/heat assist is part of the planned process route."],
+    },
+    ProcessRoute {
+        id: BushingProcessRouteId::BondedJoint,
+        label: "Bonded / Retained Joint",
+        tolerance_class: "bond line controlled",
+        recommended_ra_um: 0.8,
+        roundness_target_um: 10.0,
+        finish_machining_required: false,
+        thermal_assist_recommended: false,
+        removal_force_factor: 0.8,
+        install_force_band: (0.45, 0.8),
+        cleanup_stock: 0.0,
+        notes: &["Use when retention is shared by bond or sealant and removal planning must protect the housing."],
+    },
+];
+
+/// Look up the route definition. Every [`BushingProcessRouteId`] has an entry.
+pub fn route(id: BushingProcessRouteId) -> &'static ProcessRoute {
+    ROUTES
+        .iter()
+        .find(|r| r.id == id)
+        .expect("process library covers every route id")
+}
+
+/// Axial press force (lbf) from friction, contact pressure (ksi) and the
+/// cylindrical engagement area π·D·L.
+pub fn press_force(friction: f64, pressure_ksi: f64, bore_dia: f64, engagement_len: f64) -> f64 {
+    friction * pressure_ksi * 1000.0 * std::f64::consts::PI * bore_dia * engagement_len
+}
+
+/// Diametral stock the finish ream must remove: installed ID closure plus the
+/// route's clean-up allowance. Zero for routes without finish machining.
+fn required_finish_ream_allowance(si: &ServiceInputs<'_>, route: &ProcessRoute) -> f64 {
+    if !route.finish_machining_required {
+        return 0.0;
+    }
+    let closure = crate::service::id_closure(si.pressure, si.bore_dia, si.input.id_bushing, si.mat_b.e_ksi);
+    closure.max(0.0) + route.cleanup_stock
+}
+
+pub fn build_process_review(
+    si: &ServiceInputs<'_>,
+    physics: &PhysicsResult,
+    thermal_plan: ThermalAssistPlan,
+) -> ProcessReviewResult {
+    let input         = si.input;
+    let route         = route(input.process_route_id);
+    let entered       = input.finish_ream_allowance.unwrap_or(0.0).max(0.0);
+    let required      = required_finish_ream_allowance(si, route);
+    let assist_active = thermal_plan.active;
+    let retained      = physics.retained_install_force;
+    let install       = physics.install_force;
+
+    let mut notes: Vec<String> = route.notes.iter().map(|n| n.to_string()).collect();
+    if entered > 0.0 {
+        notes.push("Finish-ream allowance is tracked as a diametral service correction.".into());
+    }
+    if route.finish_machining_required || entered > 0.0 {
+        notes.push("Finish machining is required or strongly preferred for this route.".into());
+    }
+    if required > 0.0 && entered + crate::math::EPS < required {
+        let show = Show::new(input.units);
+        notes.push(format!(
+            "Entered finish-ream allowance {} is below the {} required to recover installed ID closure.",
+            show.length(entered, 4),
+            show.length(required, 4)
+        ));
+    }
+    if route.thermal_assist_recommended {
+        notes.push("Thermal assist is part of the selected route default.".into());
+    }
+    if assist_active {
+        notes.push("Install-state interference is reduced using the entered housing and bushing assembly temperatures before equilibrium.".into());
+    } else if route.id == BushingProcessRouteId::ThermalAssistInstall {
+        notes.push("No explicit assembly temperatures were entered; install-force guidance falls back to the selected route factors.".into());
+    }
+    if retained > PRESS_REVIEW_FORCE {
+        notes.push("Press capacity should be reviewed against the calculated install-force band.".into());
+    }
+
+    let (low, high) = if route.id == BushingProcessRouteId::ThermalAssistInstall && assist_active {
+        ACTIVE_THERMAL_BAND
+    } else {
+        route.install_force_band
+    };
+
+    ProcessReviewResult {
+        route_id: route.id,
+        route_label: route.label.into(),
+        tolerance_class: input
+            .bore_capability
+            .preferred_it_class
+            .clone()
+            .unwrap_or_else(|| route.tolerance_class.into()),
+        recommended_ra_um: route.recommended_ra_um,
+        roundness_target_um: route.roundness_target_um,
+        finish_machining_required: route.finish_machining_required || entered > 0.0,
+        thermal_assist_recommended: route.thermal_assist_recommended || retained > PRESS_REVIEW_FORCE,
+        assembly_thermal_assist_active: assist_active,
+        install_force_band: InstallForceBand { low: install * low, nominal: install, high: install * high },
+        removal_force: retained * route.removal_force_factor,
+        required_finish_ream_allowance: required,
+        thermal_plan,
+        notes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::solve_with;
+    use serde_json::json;
+
+    #[test]
+    fn every_route_is_defined() {
+        for id in [
+            BushingProcessRouteId::PressFitOnly,
+            BushingProcessRouteId::PressFitFinishReam,
+            BushingProcessRouteId::LineReamRepair,
+            BushingProcessRouteId::ThermalAssistInstall,
+            BushingProcessRouteId::BondedJoint,
+        ] {
+            assert_eq!(route(id).id, id);
+        }
+    }
+
+    #[test]
+    fn install_band_scales_with_friction_pressure_and_length() {
+        let out = solve_with(json!({ "friction": 0.2 }));
+        let p   = out.physics.contact_pressure;
+        let expected = 0.2 * p * 1000.0 * std::f64::consts::PI * 0.5 * 0.75;
+        let band = &out.process.install_force_band;
+        assert!((band.nominal - expected).abs() < 1e-6, "{} vs {expected}", band.nominal);
+        assert!((band.low - 0.85 * expected).abs() < 1e-6);
+        assert!((band.high - 1.15 * expected).abs() < 1e-6);
+        assert!((out.process.removal_force - 1.1 * out.physics.retained_install_force).abs() < 1e-6);
+        assert_eq!(out.process.required_finish_ream_allowance, 0.0);
+    }
+
+    #[test]
+    fn finish_ream_route_requires_closure_plus_stock() {
+        let out = solve_with(json!({ "processRouteId": "press_fit_finish_ream" }));
+        assert!(out.process.finish_machining_required);
+        assert!(out.process.required_finish_ream_allowance > 0.002);
+        assert!(out.process.notes.iter().any(|n| n.contains("below the")));
+    }
+
+    #[test]
+    fn high_press_force_recommends_thermal_assist() {
+        let out = solve_with(json!({ "interference": 0.004, "housingLen": 2.0 }));
+        assert!(out.physics.retained_install_force > PRESS_REVIEW_FORCE);
+        assert!(out.process.thermal_assist_recommended);
+        assert!(!out.process.assembly_thermal_assist_active);
+    }
+}
@@ -0,0 +1,272 @@
+/// Key lookup over raw input objects. Remembers the keys the parser consults
+/// so the rest can be reported as unknown, reports aliases given with
+/// differing values, values of the wrong type and unrecognised enum strings,
+/// and in schema mode records the shape of every field it is asked for.
+use std::cell::RefCell;
+
+use serde_json::{json, Value};
+
+use crate::types::{BushingWarningCode as Code, ValidationIssue, WarningSeverity};
+
+/// Stand-in object for absent nested blocks in schema mode.
+static NULL: Value = Value::Null;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Number,
+    Integer,
+    String,
+    Boolean,
+    Array,
+    Object,
+    /// A library id or an inline definition.
+    IdOrObject,
+}
+
+impl Kind {
+    fn describe(self) -> &'static str {
+        match self {
+            Kind::Number     => "a number",
+            Kind::Integer    => "a whole number",
+            Kind::String     => "a string",
+            Kind::Boolean    => "true or false",
+            Kind::Array      => "a list",
+            Kind::Object     => "an object",
+            Kind::IdOrObject => "an id or an object",
+        }
+    }
+
+    /// JSON Schema `type` keyword.
+    pub(crate) fn json_type(self) -> Value {
+        match self {
+            Kind::Number     => json!("number"),
+            Kind::Integer    => json!("integer"),
+            Kind::String     => json!("string"),
+            Kind::Boolean    => json!("boolean"),
+            Kind::Array      => json!("array"),
+            Kind::Object     => json!("object"),
+            Kind::IdOrObject => json!(["string", "object"]),
+        }
+    }
+}
+
+/// A field the parser reads, as recorded in schema mode.
+#[derive(Debug, Clone)]
+pub(crate) struct FieldShape {
+    /// JSON pointer of the enclosing object.
+    pub parent: String,
+    /// Primary key first, then its aliases.
+    pub keys: Vec<&'static str>,
+    pub kind: Kind,
+    /// Accepted strings of an enum field.
+    pub allowed: Vec<String>,
+    /// Enum strings are matched ignoring case.
+    pub folded: bool,
+}
+
+/// Findings of one parse and, in schema mode, the fields it read.
+pub(crate) struct Context {
+    severity: WarningSeverity,
+    pub issues: RefCell<Vec<ValidationIssue>>,
+    pub fields: Option<RefCell<Vec<FieldShape>>>,
+}
+
+impl Context {
+    pub(crate) fn new(severity: WarningSeverity) -> Self {
+        Self { severity, issues: RefCell::default(), fields: None }
+    }
+
+    pub(crate) fn recording() -> Self {
+        Self { fields: Some(RefCell::default()), ..Self::new(WarningSeverity::Warning) }
+    }
+}
+
+pub(crate) struct Reader<'a> {
+    v: &'a Value,
+    pointer: String,
+    known: RefCell<Vec<&'static str>>,
+    ctx: &'a Context,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn root(v: &'a Value, ctx: &'a Context) -> Self {
+        Self::new(v, String::new(), ctx)
+    }
+
+    fn new(v: &'a Value, pointer: String, ctx: &'a Context) -> Self {
+        Self { v, pointer, known: RefCell::default(), ctx }
+    }
+
+    pub(crate) fn report(&self, key: &str, code: Code, message: String, suggestion: String) {
+        let issue = ValidationIssue {
+            pointer: format!("{}/{}", self.pointer, key.replace('~', "~0").replace('/', "~1")),
+            code,
+            severity: self.ctx.severity,
+            message,
+            suggestion,
+        };
+        let mut issues = self.ctx.issues.borrow_mut();
+        if !issues.contains(&issue) {
+            issues.push(issue);
+        }
+    }
+
+    fn describe(&self, keys: &[&'static str], kind: Kind) {
+        let Some(fields) = &self.ctx.fields else {
+            return;
+        };
+        let mut fields = fields.borrow_mut();
+        if !fields.iter().any(|f| f.parent == self.pointer && f.keys[0] == keys[0]) {
+            fields.push(FieldShape { parent: self.pointer.clone(), keys: keys.to_vec(), kind, allowed: vec![], folded: false });
+        }
+    }
+
+    fn describe_choice(&self, keys: &[&'static str], allowed: &[&str], folded: bool) {
+        let Some(fields) = &self.ctx.fields else {
+            return;
+        };
+        if let Some(f) = fields.borrow_mut().iter_mut().find(|f| f.parent == self.pointer && f.keys[0] == keys[0]) {
+            f.allowed = allowed.iter().map(|a| a.to_string()).collect();
+            f.folded  = folded;
+        }
+    }
+
+    /// Non-null values present under `keys`, highest priority first.
+    pub(crate) fn lookup(&self, keys: &[&'static str]) -> Vec<(&'static str, &'a Value)> {
+        self.known.borrow_mut().extend_from_slice(keys);
+        let present: Vec<_> = keys
+            .iter()
+            .filter_map(|&k| Some((k, self.v.get(k).filter(|x| !x.is_null())?)))
+            .collect();
+        if let Some(&(first, a)) = present.first() {
+            for &(k, x) in &present[1..] {
+                let same = match (a.as_f64(), x.as_f64()) {
+                    (Some(p), Some(q)) => p == q,
+                    _                  => a == x,
+                };
+                if !same {
+                    self.report(
+                        k,
+                        Code::InputAliasConflict,
+                        format!("{k} = {x} conflicts with {first} = {a}"),
+                        format!("Remove {k}; {first} takes precedence."),
+                    );
+                }
+            }
+        }
+        present
+    }
+
+    pub(crate) fn typed<T>(&self, keys: &[&'static str], kind: Kind, conv: impl Fn(&'a Value) -> Option<T>) -> Option<T> {
+        self.describe(keys, kind);
+        let present = self.lookup(keys);
+        let found   = present.iter().find_map(|(_, x)| conv(x));
+        if let (None, Some((k, x))) = (&found, present.first()) {
+            let kind = kind.describe();
+            self.report(k, Code::InputInvalid, format!("{k} must be {kind}, got {x}"), format!("Enter {k} as {kind}."));
+        }
+        found
+    }
+
+    pub(crate) fn f(&self, keys: &[&'static str]) -> Option<f64> {
+        self.typed(keys, Kind::Number, Value::as_f64)
+    }
+
+    pub(crate) fn s(&self, keys: &[&'static str]) -> Option<String> {
+        self.typed(keys, Kind::String, |x| x.as_str().map(str::to_owned))
+    }
+
+    pub(crate) fn b(&self, keys: &[&'static str]) -> Option<bool> {
+        self.typed(keys, Kind::Boolean, Value::as_bool)
+    }
+
+    pub(crate) fn u(&self, keys: &[&'static str]) -> Option<u64> {
+        self.typed(keys, Kind::Integer, Value::as_u64)
+    }
+
+    /// Enum string under `keys` (empty when absent); a value outside
+    /// `allowed` is reported and left for the caller's default.
+    pub(crate) fn choice(&self, keys: &[&'static str], allowed: &[&str]) -> String {
+        let value = self.s(keys).unwrap_or_default();
+        self.describe_choice(keys, allowed, false);
+        if !value.is_empty() && !allowed.contains(&value.as_str()) {
+            self.invalid_choice(keys, &value, allowed);
+        }
+        value
+    }
+
+    /// As [`Reader::choice`], ignoring case and surrounding whitespace.
+    pub(crate) fn choice_folded(&self, keys: &[&'static str], allowed: &[&str]) -> String {
+        let value = self.s(keys).unwrap_or_default().trim().to_ascii_lowercase();
+        self.describe_choice(keys, allowed, true);
+        if !value.is_empty() && !allowed.contains(&value.as_str()) {
+            self.invalid_choice(keys, &value, allowed);
+        }
+        value
+    }
+
+    fn invalid_choice(&self, keys: &[&'static str], value: &str, allowed: &[&str]) {
+        let key = keys.iter().find(|k| self.v.get(**k).is_some_and(Value::is_string)).unwrap_or(&keys[0]);
+        let suggestion = match closest(value, allowed) {
+            Some(m) => format!("Did you mean '{m}'?"),
+            None    => format!("Use one of: {}.", allowed.join(", ")),
+        };
+        self.report(key, Code::InputEnumInvalid, format!("'{value}' is not a valid {key}"), suggestion);
+    }
+
+    /// Reader over the object under `keys`.
+    pub(crate) fn child(&self, keys: &[&'static str]) -> Option<Reader<'a>> {
+        self.describe(keys, Kind::Object);
+        let present = self.lookup(keys);
+        if let Some(&(k, x)) = present.iter().find(|(_, x)| x.is_object()) {
+            return Some(Reader::new(x, format!("{}/{k}", self.pointer), self.ctx));
+        }
+        if let Some((k, x)) = present.first() {
+            let kind = Kind::Object.describe();
+            self.report(k, Code::InputInvalid, format!("{k} must be {kind}, got {x}"), format!("Enter {k} as {kind}."));
+        }
+        // Schema mode descends into absent blocks so their fields are recorded
+        self.ctx.fields.as_ref().map(|_| Reader::new(&NULL, format!("{}/{}", self.pointer, keys[0]), self.ctx))
+    }
+
+    /// Report every key of this object the parser never consulted.
+    pub(crate) fn finish(&self) {
+        let known = self.known.borrow();
+        for key in self.v.as_object().into_iter().flat_map(|m| m.keys()) {
+            if !known.contains(&key.as_str()) {
+                let suggestion = match closest(key, &known) {
+                    Some(k) => format!("Did you mean '{k}'?"),
+                    None    => format!("Remove {key}."),
+                };
+                self.report(key, Code::InputUnknownKey, format!("unknown key {key}"), suggestion);
+            }
+        }
+    }
+}
+
+/// Nearest candidate within a third of the name's length (at least two edits).
+fn closest<'c>(name: &str, candidates: &[&'c str]) -> Option<&'c str> {
+    let name = name.to_ascii_lowercase();
+    candidates
+        .iter()
+        .map(|c| (edit_distance(&name, &c.to_ascii_lowercase()), *c))
+        .filter(|(d, _)| *d <= (name.len() / 3).max(2))
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c)
+}
+
+/// Levenshtein distance.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let next = (prev + usize::from(ca != *cb)).min(row[j] + 1).min(row[j + 1] + 1);
+            prev = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    row[b.len()]
+}
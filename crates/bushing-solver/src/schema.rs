@@ -1,34 +1,224 @@
-/// Input validation rules.
+/// Input validation rules. Every violation is collected with a JSON pointer
+/// to the offending field, a warning code, a severity and a suggested fix.
+/// Also emits the JSON Schema of the current input document.
 /// Ported from src/lib/core/bushing/schema.ts
-use crate::{error::BushingError, types::BushingInput};
+use serde_json::{json, Map, Value};
 
-pub fn validate(input: &BushingInput) -> Result<(), BushingError> {
-    if input.bore_dia <= 0.0 {
-        return Err(BushingError::Schema("boreDia must be > 0".into()));
+use crate::{
+    error::BushingError,
+    fit_band, flange, inputs,
+    migrate::{CURRENT_VERSION, VERSION_KEY},
+    reader::{FieldShape, Kind},
+    types::{BushingInput, BushingType, BushingWarningCode as Code, ValidationIssue, WarningSeverity},
+    units::Show,
+};
+
+#[derive(Default)]
+struct Issues(Vec<ValidationIssue>);
+
+impl Issues {
+    fn push(&mut self, pointer: &str, code: Code, severity: WarningSeverity, message: impl Into<String>, suggestion: impl Into<String>) {
+        self.0.push(ValidationIssue {
+            pointer: pointer.into(),
+            code,
+            severity,
+            message: message.into(),
+            suggestion: suggestion.into(),
+        });
+    }
+
+    fn error(&mut self, pointer: &str, code: Code, message: impl Into<String>, suggestion: impl Into<String>) {
+        self.push(pointer, code, WarningSeverity::Error, message, suggestion);
+    }
+
+    fn positive(&mut self, pointer: &str, value: f64) {
+        if value <= 0.0 {
+            self.error(pointer, Code::InputSchemaInvalid, format!("{} must be > 0", &pointer[1..]), "Enter a positive dimension.");
+        }
     }
-    if input.id_bushing <= 0.0 {
-        return Err(BushingError::Schema("idBushing must be > 0".into()));
+}
+
+/// Every violation in `input`, in field order. Errors block the solve;
+/// info-level findings are reported alongside the results. Suggested values
+/// are quoted in the input's unit system.
+pub fn check(input: &BushingInput) -> Vec<ValidationIssue> {
+    let mut v = Issues::default();
+    let show  = Show::new(input.units);
+
+    v.positive("/boreDia", input.bore_dia);
+    v.positive("/idBushing", input.id_bushing);
+    if input.id_bushing >= input.bore_dia && input.bore_dia > 0.0 {
+        v.error(
+            "/idBushing",
+            Code::BushingIdGeBore,
+            "idBushing must be < boreDia",
+            format!("Reduce idBushing below {} or enlarge boreDia.", show.length(input.bore_dia, 4)),
+        );
     }
-    if input.id_bushing >= input.bore_dia {
-        return Err(BushingError::Schema("idBushing must be < boreDia".into()));
+    if let (Some(lo), Some(hi)) = (input.bore_lower, input.bore_upper) {
+        if lo > hi {
+            v.error("/boreLower", Code::BoreLimitsReversed, "boreLower must be <= boreUpper", "Swap boreLower and boreUpper.");
+        }
     }
-    if input.housing_len <= 0.0 {
-        return Err(BushingError::Schema("housingLen must be > 0".into()));
+    if let (Some(lo), Some(hi)) = (input.interference_lower, input.interference_upper) {
+        if lo > hi {
+            v.error(
+                "/interferenceLower",
+                Code::InterferenceLimitsReversed,
+                "interferenceLower must be <= interferenceUpper",
+                "Swap interferenceLower and interferenceUpper.",
+            );
+        }
     }
-    if input.housing_width <= 0.0 {
-        return Err(BushingError::Schema("housingWidth must be > 0".into()));
+    let cap = &input.bore_capability;
+    if let (Some(min), Some(max)) = (cap.min_achievable_tol_width, cap.max_recommended_tol_width) {
+        if min > max {
+            v.error(
+                "/boreCapability/minAchievableTolWidth",
+                Code::BoreCapabilityRangeInvalid,
+                "minAchievableTolWidth must be <= maxRecommendedTolWidth",
+                format!("Lower minAchievableTolWidth to at most {} or raise maxRecommendedTolWidth.", show.length(max, 4)),
+            );
+        }
     }
-    if input.edge_dist <= 0.0 {
-        return Err(BushingError::Schema("edgeDist must be > 0".into()));
+    let policy = &input.interference_policy;
+    if policy.preserve_bore_nominal == Some(true) && policy.allow_bore_nominal_shift == Some(true) {
+        v.push(
+            "/interferencePolicy/allowBoreNominalShift",
+            Code::PolicyPreserveShiftConflict,
+            WarningSeverity::Info,
+            "preserveBoreNominal and allowBoreNominalShift are both set; preserving the nominal takes precedence",
+            "Clear one of preserveBoreNominal or allowBoreNominalShift.",
+        );
+    }
+    if cap.mode.as_deref() == Some("reamer_fixed") && policy.lock_bore == Some(false) {
+        v.push(
+            "/interferencePolicy/lockBore",
+            Code::ReamerLockConflict,
+            WarningSeverity::Info,
+            "a reamer-fixed bore capability requires the bore to stay locked",
+            "Set interferencePolicy.lockBore to true or change boreCapability.mode.",
+        );
+    }
+    v.positive("/housingLen", input.housing_len);
+    v.positive("/housingWidth", input.housing_width);
+    v.positive("/edgeDist", input.edge_dist);
+    if input.edge_dist > 0.0 && input.edge_dist <= input.bore_dia / 2.0 {
+        v.error(
+            "/edgeDist",
+            Code::InputSchemaInvalid,
+            "edgeDist must be > boreDia / 2 (bore breaks out of the edge)",
+            format!("Increase edgeDist above {}.", show.length(input.bore_dia / 2.0, 4)),
+        );
+    }
+    let side = input.bore_dia + 2.0 * input.hole_offset.unwrap_or(0.0).abs();
+    if input.housing_width > 0.0 && input.housing_width <= side {
+        v.error(
+            "/housingWidth",
+            Code::InputSchemaInvalid,
+            "housingWidth must exceed boreDia + 2·|holeOffset| (bore breaks out of the side)",
+            format!("Increase housingWidth above {} or reduce holeOffset.", show.length(side, 4)),
+        );
     }
     if input.cs_angle < 60.0 || input.cs_angle > 160.0 {
-        return Err(BushingError::Schema(
-            "csAngle must be between 60° and 160°".into(),
-        ));
+        v.error("/csAngle", Code::InternalCsAngleInvalid, "csAngle must be between 60° and 160°", "Use a standard countersink angle such as 100°.");
+    }
+    if input.bushing_type == BushingType::Flanged {
+        if !matches!(flange::flange_od(input), Some(d) if d > input.bore_dia) {
+            v.error(
+                "/flangeOd",
+                Code::InputSchemaInvalid,
+                "flangeOd must be > boreDia for flanged bushings",
+                format!("Enter a flange diameter above {}.", show.length(input.bore_dia, 4)),
+            );
+        }
+        if !matches!(input.flange_thk, Some(t) if t > 0.0) {
+            v.error("/flangeThk", Code::InputSchemaInvalid, "flangeThk must be > 0 for flanged bushings", "Enter the flange thickness.");
+        }
+    }
+    if matches!(input.fitting_factor, Some(f) if f < 1.0) {
+        v.error("/fittingFactor", Code::InputSchemaInvalid, "fittingFactor must be >= 1", "Use 1.0 for no fitting factor.");
+    }
+    if matches!(input.assembly_clearance_target, Some(c) if c < 0.0) {
+        v.error(
+            "/assemblyClearanceTarget",
+            Code::InputSchemaInvalid,
+            "assemblyClearanceTarget must be >= 0",
+            "Enter the desired assembly clearance as a positive value or leave it unset.",
+        );
+    }
+    if matches!(input.duty_cycle_pct, Some(d) if !(0.0..=100.0).contains(&d)) {
+        v.error("/dutyCyclePct", Code::InputSchemaInvalid, "dutyCyclePct must be between 0 and 100", "Enter the duty cycle as a percentage.");
+    }
+    if matches!(input.monte_carlo_samples, Some(n) if n == 0 || n > fit_band::MAX_SAMPLES) {
+        v.error(
+            "/monteCarloSamples",
+            Code::InputSchemaInvalid,
+            "monteCarloSamples must be between 1 and 1,000,000",
+            "Use 10,000 samples for a typical run.",
+        );
+    }
+    v.0
+}
+
+/// Fail with every finding when any of them is an error.
+pub fn validate(input: &BushingInput) -> Result<(), BushingError> {
+    let issues = check(input);
+    if issues.iter().any(|i| i.severity == WarningSeverity::Error) {
+        return Err(BushingError::Schema(issues));
     }
     Ok(())
 }
 
+/// JSON Schema (draft 2020-12) of the current input document, generated from
+/// the fields the parser reads so it cannot drift from it. Aliases are listed
+/// next to their primary key; each required field may be given under any alias.
+pub fn json_schema() -> Value {
+    let (fields, required) = inputs::describe();
+    let mut root = object_schema("", &fields);
+    root["$schema"] = json!("https://json-schema.org/draft/2020-12/schema");
+    root["title"]   = json!(format!("Bushing input, {VERSION_KEY} {CURRENT_VERSION}"));
+    root["properties"][VERSION_KEY]["const"] = json!(CURRENT_VERSION);
+    root["allOf"] = required
+        .iter()
+        .map(|keys| json!({ "anyOf": keys.iter().map(|k| json!({ "required": [k] })).collect::<Vec<_>>() }))
+        .collect();
+    root
+}
+
+fn either_case(c: char) -> String {
+    if c.is_ascii_alphabetic() {
+        format!("[{}{}]", c.to_ascii_lowercase(), c.to_ascii_uppercase())
+    } else {
+        c.to_string()
+    }
+}
+
+fn object_schema(pointer: &str, fields: &[FieldShape]) -> Value {
+    let mut properties = Map::new();
+    for f in fields.iter().filter(|f| f.parent == pointer) {
+        let mut spec = match f.kind {
+            Kind::Object => object_schema(&format!("{pointer}/{}", f.keys[0]), fields),
+            kind         => json!({ "type": kind.json_type() }),
+        };
+        if f.folded {
+            // ECMA-262 patterns have no case-insensitive flag
+            let words: Vec<String> = f.allowed.iter().map(|w| w.chars().map(either_case).collect()).collect();
+            spec["pattern"] = json!(format!("^\\s*({})\\s*$", words.join("|")));
+        } else if !f.allowed.is_empty() {
+            spec["enum"] = json!(f.allowed);
+        }
+        for (i, key) in f.keys.iter().enumerate() {
+            let mut alias = spec.clone();
+            if i > 0 {
+                alias["description"] = json!(format!("Alias of {}", f.keys[0]));
+            }
+            properties.insert(key.to_string(), alias);
+        }
+    }
+    json!({ "type": "object", "properties": properties, "additionalProperties": false })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,7 +226,7 @@ mod tests {
 
     fn base_input() -> BushingInput {
         BushingInput {
-            units: "imperial".into(),
+            units: UnitSystem::Imperial,
             bore_dia: 0.5005,
             id_bushing: 0.25,
             interference: 0.0015,
@@ -59,6 +249,7 @@ mod tests {
             housing_len: 0.75,
             housing_width: 1.5,
             edge_dist: 0.75,
+            hole_offset: None,
             bushing_type: BushingType::Straight,
             id_type: IdType::Straight,
             cs_mode: CsMode::DepthAngle,
@@ -78,10 +269,13 @@ mod tests {
             flange_thk: None,
             mat_housing: "Al_7075_T6".into(),
             mat_bushing: "SS_17_4_PH".into(),
+            material_basis: MaterialBasis::S,
+            custom_materials: vec![],
             friction: 0.15,
             d_t: 0.0,
             assembly_housing_temperature: None,
             assembly_bushing_temperature: None,
+            assembly_clearance_target: None,
             process_route_id: BushingProcessRouteId::PressFitOnly,
             standards_basis: BushingStandardsBasis::ShopDefault,
             standards_revision: None,
@@ -91,7 +285,11 @@ mod tests {
             min_wall_straight: 0.010,
             min_wall_neck: 0.005,
             end_constraint: EndConstraint::Free,
+            failure_criterion: FailureCriterion::VonMises,
+            allowable_basis: AllowableBasis::Yield,
+            fitting_factor: None,
             load: None,
+            axial_load: None,
             edge_load_angle_deg: None,
             service_temperature_hot: None,
             service_temperature_cold: None,
@@ -108,6 +306,11 @@ mod tests {
             misalignment_deg: None,
             id_cs: None,
             od_cs: None,
+            measured_part: None,
+            analysis_mode: BushingAnalysisMode::Nominal,
+            monte_carlo_samples: None,
+            monte_carlo_seed: None,
+            d_t_std_dev: None,
         }
     }
 
@@ -122,4 +325,75 @@ mod tests {
         inp.id_bushing = inp.bore_dia; // ID == bore → should fail
         assert!(validate(&inp).is_err());
     }
+
+    #[test]
+    fn every_violation_is_reported_with_its_field() {
+        let mut inp = base_input();
+        inp.bore_lower = Some(0.501);
+        inp.bore_upper = Some(0.500);
+        inp.interference_lower = Some(0.002);
+        inp.interference_upper = Some(0.001);
+        inp.bore_capability.min_achievable_tol_width = Some(0.002);
+        inp.bore_capability.max_recommended_tol_width = Some(0.001);
+        inp.housing_len = 0.0;
+        let err = validate(&inp).unwrap_err();
+        let pointers: Vec<_> = err.issues().iter().map(|i| (i.pointer.as_str(), i.code)).collect();
+        assert_eq!(
+            pointers,
+            [
+                ("/boreLower", BushingWarningCode::BoreLimitsReversed),
+                ("/interferenceLower", BushingWarningCode::InterferenceLimitsReversed),
+                ("/boreCapability/minAchievableTolWidth", BushingWarningCode::BoreCapabilityRangeInvalid),
+                ("/housingLen", BushingWarningCode::InputSchemaInvalid),
+            ]
+        );
+        assert!(err.issues().iter().all(|i| i.severity == WarningSeverity::Error && !i.suggestion.is_empty()));
+        assert!(err.to_string().contains("/housingLen: housingLen must be > 0"));
+    }
+
+    #[test]
+    fn suggestions_quote_values_in_the_input_units() {
+        let mut inp = base_input();
+        inp.units      = UnitSystem::Metric;
+        inp.id_bushing = inp.bore_dia;
+        let err = validate(&inp).unwrap_err();
+        assert_eq!(err.issues()[0].suggestion, "Reduce idBushing below 12.7127 mm or enlarge boreDia.");
+    }
+
+    #[test]
+    fn policy_conflicts_do_not_block() {
+        let mut inp = base_input();
+        inp.interference_policy.preserve_bore_nominal = Some(true);
+        inp.interference_policy.allow_bore_nominal_shift = Some(true);
+        inp.bore_capability.mode = Some("reamer_fixed".into());
+        inp.interference_policy.lock_bore = Some(false);
+        assert!(validate(&inp).is_ok());
+        let codes: Vec<_> = check(&inp).iter().map(|i| (i.code, i.severity)).collect();
+        assert_eq!(
+            codes,
+            [
+                (BushingWarningCode::PolicyPreserveShiftConflict, WarningSeverity::Info),
+                (BushingWarningCode::ReamerLockConflict, WarningSeverity::Info),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_schema_tracks_the_parser() {
+        let schema = json_schema();
+        let props  = &schema["properties"];
+        assert_eq!(props["boreDia"]["type"], "number");
+        assert_eq!(props["bore_dia"]["description"], "Alias of boreDia");
+        assert_eq!(props["bushingType"]["enum"], json!(["straight", "flanged", "countersink"]));
+        assert_eq!(props["matHousing"]["type"], json!(["string", "object"]));
+        assert_eq!(props["schemaVersion"]["const"], CURRENT_VERSION);
+        assert_eq!(props["interferencePolicy"]["properties"]["lockBore"]["type"], "boolean");
+        assert_eq!(props["measuredPart"]["properties"]["bore"]["properties"]["readings"]["type"], "array");
+        assert_eq!(schema["additionalProperties"], false);
+        assert_eq!(props["materialBasis"]["pattern"], "^\\s*([aA]|[bB]|[sS])\\s*$");
+        // Legacy aliases are migrated, not part of the current schema
+        assert!(props.get("bushID").is_none() && props.get("thetaDeg").is_none());
+        assert_eq!(schema["allOf"].as_array().unwrap().len(), 6);
+        assert_eq!(schema["allOf"][0]["anyOf"][1]["required"][0], "bore_dia");
+    }
 }
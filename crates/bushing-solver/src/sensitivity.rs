@@ -0,0 +1,200 @@
+/// Sensitivity report: central-difference derivatives of contact pressure,
+/// hoop stresses and governing margin with respect to every set numeric
+/// input, ranked by normalized influence.
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    approval::RuleBook,
+    error::BushingError,
+    materials::MaterialLibrary,
+    math::EPS,
+    schema,
+    solver,
+    sweep,
+    types::*,
+    units::{self, Scale},
+};
+
+/// Relative finite-difference step; inputs at zero step by `ABS_STEP`.
+const REL_STEP: f64 = 1e-4;
+const ABS_STEP: f64 = 1e-6;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct OutputSet {
+    pub pressure: f64,
+    pub housing_hoop: f64,
+    pub bushing_hoop: f64,
+    pub governing_margin: f64,
+}
+
+impl OutputSet {
+    fn of(out: &BushingOutput) -> Self {
+        Self {
+            pressure: out.pressure,
+            housing_hoop: out.hoop.housing_sigma,
+            bushing_hoop: out.hoop.bushing_sigma,
+            governing_margin: out.governing.margin,
+        }
+    }
+
+    fn map(self, f: impl Fn(f64) -> f64) -> Self {
+        self.map2(self, |a, _| f(a))
+    }
+
+    fn map2(self, o: Self, f: impl Fn(f64, f64) -> f64) -> Self {
+        Self {
+            pressure: f(self.pressure, o.pressure),
+            housing_hoop: f(self.housing_hoop, o.housing_hoop),
+            bushing_hoop: f(self.bushing_hoop, o.bushing_hoop),
+            governing_margin: f(self.governing_margin, o.governing_margin),
+        }
+    }
+
+    fn max_abs(&self) -> f64 {
+        [self.pressure, self.housing_hoop, self.bushing_hoop, self.governing_margin]
+            .iter()
+            .fold(0.0, |m, x| m.max(x.abs()))
+    }
+}
+
+/// Values, steps and derivatives are in the input's unit system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputSensitivity {
+    pub field: String,
+    pub value: f64,
+    pub step: f64,
+    /// `central` or `forward` / `backward` where one side fails validation.
+    pub scheme: String,
+    pub derivatives: OutputSet,
+    /// d(ln y)/d(ln x); the margin is taken as the stress ratio 1 + MS.
+    pub elasticities: OutputSet,
+    /// Largest absolute elasticity across the outputs; the ranking key.
+    pub influence: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitivityReport {
+    pub baseline: OutputSet,
+    pub governing: BushingCandidate,
+    /// Highest influence first.
+    pub inputs: Vec<InputSensitivity>,
+}
+
+fn solve_at(base: &BushingInput, field: &str, value: f64, rules: &RuleBook, library: &MaterialLibrary) -> Option<OutputSet> {
+    let mut input = base.clone();
+    sweep::set_field(&mut input, field, value).ok()?;
+    schema::validate(&input).ok()?;
+    solver::compute_with(&input, rules, library).ok().map(|out| OutputSet::of(&out))
+}
+
+fn elasticity(derivative: f64, x: f64, y: f64) -> f64 {
+    if y.abs() > EPS { derivative * x / y } else { 0.0 }
+}
+
+fn differentiate(
+    base: &BushingInput,
+    baseline: OutputSet,
+    field: &str,
+    x: f64,
+    rules: &RuleBook,
+    library: &MaterialLibrary,
+) -> Option<InputSensitivity> {
+    let h = if x.abs() > EPS { REL_STEP * x.abs() } else { ABS_STEP };
+    let (scheme, derivatives) = match (solve_at(base, field, x + h, rules, library), solve_at(base, field, x - h, rules, library)) {
+        (Some(up), Some(dn)) => ("central", up.map2(dn, |a, b| (a - b) / (2.0 * h))),
+        (Some(up), None)     => ("forward", up.map2(baseline, |a, b| (a - b) / h)),
+        (None, Some(dn))     => ("backward", baseline.map2(dn, |a, b| (a - b) / h)),
+        (None, None)         => return None,
+    };
+    let reference    = OutputSet { governing_margin: 1.0 + baseline.governing_margin, ..baseline };
+    let elasticities = derivatives.map2(reference, |d, y| elasticity(d, x, y));
+    // Outputs are already in the input's system; express x and the step there too
+    let k   = Scale::from_internal(base.units);
+    let dim = units::dimension(field);
+    Some(InputSensitivity {
+        field: field.into(),
+        value: k.value(dim, x),
+        step: h * k.factor(dim),
+        scheme: scheme.into(),
+        derivatives: derivatives.map(|d| d / k.factor(dim)),
+        influence: elasticities.max_abs(),
+        elasticities,
+    })
+}
+
+/// Differentiate the key outputs with respect to every set floating-point
+/// field of `input`, solving against `rules` with materials from `library`.
+/// Fields whose perturbation fails validation on both sides are left out.
+pub fn analyze(input: &BushingInput, rules: &RuleBook, library: &MaterialLibrary) -> Result<SensitivityReport, BushingError> {
+    let out      = solver::compute_with(input, rules, library)?;
+    let baseline = OutputSet::of(&out);
+    let fields   = serde_json::to_value(input)?;
+    let mut inputs: Vec<InputSensitivity> = fields
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(_, v)| v.is_f64())
+        .filter_map(|(k, v)| differentiate(input, baseline, k, v.as_f64()?, rules, library))
+        .collect();
+    inputs.sort_by(|a, b| b.influence.total_cmp(&a.influence));
+    Ok(SensitivityReport { baseline, governing: out.governing, inputs })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{input_with, metric};
+    use serde_json::json;
+
+    fn analyze(input: &BushingInput) -> Result<SensitivityReport, BushingError> {
+        super::analyze(input, RuleBook::builtin(), MaterialLibrary::builtin())
+    }
+
+    fn entry<'a>(r: &'a SensitivityReport, field: &str) -> &'a InputSensitivity {
+        r.inputs.iter().find(|s| s.field == field).unwrap_or_else(|| panic!("missing {field}"))
+    }
+
+    #[test]
+    fn pressure_is_linear_in_interference() {
+        let r = analyze(&input_with(json!({}))).unwrap();
+        let i = entry(&r, "interference");
+        assert_eq!(i.scheme, "central");
+        // Lamé pressure is proportional to the diametral interference
+        assert!((i.derivatives.pressure - r.baseline.pressure / 0.0015).abs() / r.baseline.pressure < 1e-6);
+        assert!((i.elasticities.pressure - 1.0).abs() < 1e-6);
+        assert!(entry(&r, "d_t").derivatives.pressure.abs() > 0.0);
+    }
+
+    #[test]
+    fn metric_values_and_derivatives_are_per_input_unit() {
+        let metric = input_with(metric(json!({})));
+        let r = analyze(&metric).unwrap();
+        let i = entry(&r, "interference");
+        assert!((i.value - 0.0381).abs() < 1e-12);
+        assert!((i.derivatives.pressure - r.baseline.pressure / i.value).abs() / r.baseline.pressure < 1e-5);
+        assert!((i.elasticities.pressure - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ranked_by_influence_and_skips_unset_fields() {
+        let r = analyze(&input_with(json!({}))).unwrap();
+        assert!(r.inputs.windows(2).all(|w| w[0].influence >= w[1].influence));
+        assert!(!r.inputs.iter().any(|s| s.field == "load" || s.field == "monte_carlo_samples"));
+        // Outputs are independent of the friction coefficient
+        assert_eq!(entry(&r, "friction").influence, 0.0);
+        assert!(entry(&r, "interference").influence > entry(&r, "housing_len").influence);
+    }
+
+    #[test]
+    fn one_sided_at_validation_boundary() {
+        // Hoop-governed fit at the lowest permitted fitting factor
+        let r = analyze(&input_with(json!({
+            "fittingFactor": 1.0, "allowableBasis": "ultimate",
+            "interference": 0.004, "edgeDist": 2.0, "housingWidth": 3.0,
+        })))
+        .unwrap();
+        let ff = entry(&r, "fitting_factor");
+        assert_eq!(ff.scheme, "forward");
+        assert!(ff.derivatives.governing_margin < 0.0);
+    }
+}
@@ -1,85 +1,422 @@
-/// Service envelope analysis, duty screening, process review, approval review.
-/// Stub — full port from serviceAnalysis.ts is Task #10.
-use crate::types::*;
+/// Service envelope analysis and duty screening.
+/// Ported from src/lib/core/bushing/serviceAnalysis.ts
+use crate::{materials, types::*, units::Show};
+
+/// Reference (free-state) temperature for absolute service temperatures, °F.
+pub(crate) const REFERENCE_TEMP_F: f64 = 70.0;
+/// Diametral band (inches) inside which a fit is classed as transition.
+const SERVICE_GAP_THRESHOLD: f64 = 0.00005;
 
 pub struct ServiceInputs<'a> {
     pub input: &'a BushingInput,
+    pub mat_h: &'a MaterialProps,
+    pub mat_b: &'a MaterialProps,
+    pub bore_dia: f64,
+    /// Diametral interference before thermal correction.
+    pub delta_user: f64,
     pub pressure: f64,
     pub od_bushing: f64,
 }
 
+/// Convert an absolute temperature (°F) to a differential from the free-state reference.
+pub(crate) fn temperature_delta_f(value: f64) -> f64 {
+    value - REFERENCE_TEMP_F
+}
+
+fn fit_class_for(effective_interference: f64) -> &'static str {
+    if effective_interference < -SERVICE_GAP_THRESHOLD {
+        "clearance"
+    } else if effective_interference.abs() <= SERVICE_GAP_THRESHOLD {
+        "transition"
+    } else {
+        "interference"
+    }
+}
+
+fn fit_class_rank(fit_class: &str) -> u8 {
+    match fit_class {
+        "clearance"  => 0,
+        "transition" => 1,
+        _            => 2,
+    }
+}
+
+/// Radial ID closure (diametral, inches) of a thick bushing under external pressure `p` (ksi).
+pub(crate) fn id_closure(p: f64, od: f64, id: f64, e_ksi: f64) -> f64 {
+    let den = e_ksi * (od * od - id * id);
+    if den <= 0.0 {
+        return 0.0;
+    }
+    2.0 * p * od * od * id / den
+}
+
+/// Evaluate one installed state at a uniform temperature `d_t_f` (°F from
+/// reference), with both materials derated to that temperature.
+fn service_state(si: &ServiceInputs<'_>, id: &str, label: &str, d_t_f: f64, note: String) -> BushingServiceState {
+    let input = si.input;
+    let mat_h = materials::at_temperature(si.mat_h, REFERENCE_TEMP_F + d_t_f);
+    let mat_b = materials::at_temperature(si.mat_b, REFERENCE_TEMP_F + d_t_f);
+    let point = crate::solver::solve_point(input, &mat_h, &mat_b, si.bore_dia, si.delta_user, d_t_f);
+    let free_id_at_temp = input.id_bushing * (1.0 + mat_b.alpha_u_f * 1e-6 * d_t_f);
+    let closure = id_closure(point.pressure, si.bore_dia, input.id_bushing, mat_b.e_ksi);
+    let projected_id = free_id_at_temp - closure;
+    BushingServiceState {
+        id: id.into(),
+        label: label.into(),
+        effective_interference: point.delta_total,
+        contact_pressure: point.pressure,
+        projected_id,
+        id_change_from_free: projected_id - input.id_bushing,
+        fit_class: fit_class_for(point.delta_total).into(),
+        margin: point.hoop.housing_ms.min(point.hoop.bushing_ms),
+        note,
+    }
+}
+
+/// Free, installed, hot- and cold-service states. Hot/cold states are only
+/// evaluated when the corresponding service temperature is entered.
+/// `id_change_from_free` is signed: negative values are ID closure.
 pub fn build_service_envelope(si: &ServiceInputs<'_>) -> ServiceEnvelopeResult {
+    let input = si.input;
     let free_state = BushingServiceState {
         id: "free".into(),
         label: "Free (pre-install)".into(),
-        effective_interference: si.input.interference,
+        effective_interference: 0.0,
         contact_pressure: 0.0,
-        projected_id: si.input.id_bushing,
-        id_change_from_free: 0.0,
-        fit_class: "interference".into(),
-        note: "".into(),
-    };
-    let installed_state = BushingServiceState {
-        id: "installed".into(),
-        label: "Installed".into(),
-        effective_interference: si.input.interference,
-        contact_pressure: si.pressure,
-        projected_id: si.input.id_bushing,
+        projected_id: input.id_bushing,
         id_change_from_free: 0.0,
-        fit_class: "interference".into(),
-        note: "".into(),
+        fit_class: "clearance".into(),
+        margin: 999.0,
+        note: "Unassembled reference condition with no interference closure.".into(),
     };
+    let mut states = vec![
+        free_state,
+        service_state(si, "installed", "Installed", input.d_t, "Installed condition at the analysis temperature differential.".into()),
+    ];
+    let show = Show::new(input.units);
+    if let Some(hot) = input.service_temperature_hot {
+        let d_t_f = temperature_delta_f(hot);
+        states.push(service_state(si, "hot", "Hot Service", d_t_f, format!("Uniform soak at {} ({} from reference).", show.temperature(hot, 0), show.delta_t(d_t_f, 0))));
+    }
+    if let Some(cold) = input.service_temperature_cold {
+        let d_t_f = temperature_delta_f(cold);
+        states.push(service_state(si, "cold", "Cold Service", d_t_f, format!("Uniform soak at {} ({} from reference).", show.temperature(cold, 0), show.delta_t(d_t_f, 0))));
+    }
+
+    let governing = states
+        .iter()
+        .filter(|st| st.id != "free")
+        .min_by(|a, b| {
+            fit_class_rank(&a.fit_class)
+                .cmp(&fit_class_rank(&b.fit_class))
+                .then(a.effective_interference.total_cmp(&b.effective_interference))
+                .then(b.contact_pressure.total_cmp(&a.contact_pressure))
+        })
+        .expect("installed state is always present");
+    let governing_state_id    = governing.id.clone();
+    let governing_state_label = governing.label.clone();
+
     ServiceEnvelopeResult {
-        states: vec![free_state, installed_state],
-        governing_state_id: "installed".into(),
-        governing_state_label: "Installed".into(),
-        finish_machining_required: false,
+        states,
+        governing_state_id,
+        governing_state_label,
+        finish_machining_required: input.finish_ream_allowance.is_some_and(|a| a > 0.0),
     }
 }
 
-pub fn build_duty_screen(input: &BushingInput) -> DutyScreenResult {
+/// PV limit (MPa·m/s) and Archard dimensional wear coefficient (mm³/N·m)
+/// for a bushing family running against a steel pin.
+struct WearLimits {
+    family: WearFamily,
+    lubrication: BushingLubricationMode,
+    pv_limit: f64,
+    wear_coeff: f64,
+}
+
+#[rustfmt::skip]
+static WEAR_TABLE: &[WearLimits] = &[
+    WearLimits { family: WearFamily::Bronze,   lubrication: BushingLubricationMode::Dry,       pv_limit: 0.50, wear_coeff: 1.0e-5 },
+    WearLimits { family: WearFamily::Bronze,   lubrication: BushingLubricationMode::Greased,   pv_limit: 1.75, wear_coeff: 1.0e-6 },
+    WearLimits { family: WearFamily::Bronze,   lubrication: BushingLubricationMode::Oiled,     pv_limit: 3.50, wear_coeff: 5.0e-7 },
+    WearLimits { family: WearFamily::Bronze,   lubrication: BushingLubricationMode::SolidFilm, pv_limit: 1.00, wear_coeff: 3.0e-6 },
+    WearLimits { family: WearFamily::Steel,    lubrication: BushingLubricationMode::Dry,       pv_limit: 0.20, wear_coeff: 5.0e-5 },
+    WearLimits { family: WearFamily::Steel,    lubrication: BushingLubricationMode::Greased,   pv_limit: 1.00, wear_coeff: 2.0e-6 },
+    WearLimits { family: WearFamily::Steel,    lubrication: BushingLubricationMode::Oiled,     pv_limit: 1.75, wear_coeff: 1.0e-6 },
+    WearLimits { family: WearFamily::Steel,    lubrication: BushingLubricationMode::SolidFilm, pv_limit: 0.70, wear_coeff: 5.0e-6 },
+    WearLimits { family: WearFamily::Titanium, lubrication: BushingLubricationMode::Dry,       pv_limit: 0.05, wear_coeff: 3.0e-4 },
+    WearLimits { family: WearFamily::Titanium, lubrication: BushingLubricationMode::Greased,   pv_limit: 0.35, wear_coeff: 1.0e-5 },
+    WearLimits { family: WearFamily::Titanium, lubrication: BushingLubricationMode::Oiled,     pv_limit: 0.50, wear_coeff: 5.0e-6 },
+    WearLimits { family: WearFamily::Titanium, lubrication: BushingLubricationMode::SolidFilm, pv_limit: 0.50, wear_coeff: 2.0e-5 },
+    WearLimits { family: WearFamily::Aluminum, lubrication: BushingLubricationMode::Dry,       pv_limit: 0.10, wear_coeff: 2.0e-4 },
+    WearLimits { family: WearFamily::Aluminum, lubrication: BushingLubricationMode::Greased,   pv_limit: 0.50, wear_coeff: 1.0e-5 },
+    WearLimits { family: WearFamily::Aluminum, lubrication: BushingLubricationMode::Oiled,     pv_limit: 0.70, wear_coeff: 5.0e-6 },
+    WearLimits { family: WearFamily::Aluminum, lubrication: BushingLubricationMode::SolidFilm, pv_limit: 0.35, wear_coeff: 2.0e-5 },
+];
+
+const PSI_TO_MPA: f64 = 0.006_894_757;
+const IN_TO_M: f64 = 0.0254;
+/// Radial wear depth used for the life estimate when no wear allowance is entered (inches).
+const DEFAULT_WEAR_DEPTH: f64 = 0.005;
+
+/// PV limit and wear coefficient for `family`; without one, the lowest limit
+/// and highest coefficient of any family at this lubrication.
+fn wear_limits(family: Option<WearFamily>, lubrication: BushingLubricationMode) -> (f64, f64) {
+    let rows = WEAR_TABLE
+        .iter()
+        .filter(|w| w.lubrication == lubrication && family.is_none_or(|f| w.family == f));
+    rows.fold((f64::INFINITY, 0.0), |(pv, k), w| (pv.min(w.pv_limit), k.max(w.wear_coeff)))
+}
+
+fn contamination_factor(level: BushingContaminationLevel) -> f64 {
+    match level {
+        BushingContaminationLevel::Clean    => 1.0,
+        BushingContaminationLevel::Shop     => 0.86,
+        BushingContaminationLevel::Dirty    => 0.68,
+        BushingContaminationLevel::Abrasive => 0.5,
+    }
+}
+
+fn roughness_factor(ra_um: f64) -> f64 {
+    (1.15 - (ra_um - 1.6).max(0.0) * 0.09 - (1.6 - ra_um).max(0.0) * 0.01).clamp(0.55, 1.2)
+}
+
+fn hardness_factor(hrc: f64) -> f64 {
+    (1.1 - (36.0 - hrc).max(0.0) * 0.03).clamp(0.6, 1.1)
+}
+
+fn misalignment_factor(deg: f64) -> f64 {
+    (1.0 - deg.abs() * 0.08).clamp(0.6, 1.0)
+}
+
+fn wear_risk_for(score: f64) -> WearRisk {
+    if score >= 75.0 {
+        WearRisk::Severe
+    } else if score >= 50.0 {
+        WearRisk::High
+    } else if score >= 25.0 {
+        WearRisk::Moderate
+    } else {
+        WearRisk::Low
+    }
+}
+
+/// Surface sliding velocity at the bushing ID (m/s), averaged over the duty cycle.
+fn sliding_velocity_mps(input: &BushingInput) -> f64 {
+    let duty  = (input.duty_cycle_pct.unwrap_or(100.0) / 100.0).clamp(0.0, 1.0);
+    let freq  = input.oscillation_freq_hz.unwrap_or(0.0).max(0.0);
+    let d_m   = input.id_bushing.max(0.0) * IN_TO_M;
+    match input.load_spectrum {
+        BushingLoadSpectrum::Static      => 0.0,
+        BushingLoadSpectrum::Rotating    => std::f64::consts::PI * d_m * freq * duty,
+        // Full swing of ±angle/2 out and back each cycle
+        BushingLoadSpectrum::Oscillating => {
+            d_m * input.oscillation_angle_deg.unwrap_or(0.0).max(0.0).to_radians() * freq * duty
+        }
+    }
+}
+
+/// PV / wear screen of the pin running in the bushing ID.
+///
+/// Specific load is `load / (ID × housing_len)`. The PV limit comes from the
+/// family/lubrication table (the bushing material's `wear_family`), derated for contamination, finish, shaft hardness,
+/// misalignment and hot-service temperature. Life is an Archard estimate of the
+/// time to wear through `wear_allowance` (or 0.005 in) radially.
+pub fn build_duty_screen(input: &BushingInput, mat_b: &MaterialProps) -> DutyScreenResult {
+    let (pv_base, wear_base) = wear_limits(mat_b.wear_family, input.lubrication_mode);
+    let roughness    = input.surface_roughness_ra_um.unwrap_or(1.6);
+    let hardness     = input.shaft_hardness_hrc.unwrap_or(32.0);
+    let misalignment = input.misalignment_deg.unwrap_or(0.0).abs();
+    let temperature_factor = input
+        .service_temperature_hot
+        .map(|t| (1.0 - (temperature_delta_f(t) - 80.0).max(0.0) * 0.0015).clamp(0.75, 1.0))
+        .unwrap_or(1.0);
+
+    let projected_area    = (input.id_bushing * input.housing_len).max(1e-9);
+    let specific_load_psi = input.load.unwrap_or(0.0).abs() / projected_area;
+    let specific_load_mpa = specific_load_psi * PSI_TO_MPA;
+    let sliding_velocity_mps = sliding_velocity_mps(input);
+    let pv = specific_load_mpa * sliding_velocity_mps;
+
+    let derate = contamination_factor(input.contamination_level)
+        * roughness_factor(roughness)
+        * hardness_factor(hardness)
+        * misalignment_factor(misalignment)
+        * temperature_factor;
+    let pv_limit       = pv_base * derate;
+    let pv_utilization = if pv_limit > 0.0 { pv / pv_limit } else { 0.0 };
+
+    let utilization_score   = (pv_utilization * 35.0).clamp(0.0, 60.0);
+    let contamination_score = match input.contamination_level {
+        BushingContaminationLevel::Clean    => 0.0,
+        BushingContaminationLevel::Shop     => 8.0,
+        BushingContaminationLevel::Dirty    => 18.0,
+        BushingContaminationLevel::Abrasive => 25.0,
+    };
+    let roughness_score    = ((roughness - 1.6).max(0.0) * 9.0).clamp(0.0, 16.0);
+    let hardness_score     = ((34.0 - hardness).max(0.0) * 2.2).clamp(0.0, 14.0);
+    let misalignment_score = (misalignment * 8.0).clamp(0.0, 14.0);
+    let spectrum_score = match input.load_spectrum {
+        BushingLoadSpectrum::Static      => 0.0,
+        BushingLoadSpectrum::Oscillating => 4.0,
+        BushingLoadSpectrum::Rotating    => 8.0,
+    };
+    let lubrication_score = if input.lubrication_mode == BushingLubricationMode::Dry { 6.0 } else { 0.0 };
+    let risk_score = (utilization_score
+        + contamination_score
+        + roughness_score
+        + hardness_score
+        + misalignment_score
+        + spectrum_score
+        + lubrication_score)
+        .clamp(0.0, 100.0);
+
+    let mut drivers = vec![
+        (format!("PV utilization {pv_utilization:.2}x limit"), utilization_score),
+        (format!("Contamination: {:?}", input.contamination_level).to_lowercase(), contamination_score),
+        (format!("Surface roughness Ra {roughness:.2} um"), roughness_score),
+        (format!("Shaft hardness {hardness:.0} HRC"), hardness_score),
+        (format!("Misalignment {misalignment:.2} deg"), misalignment_score),
+        (format!("{:?} load spectrum", input.load_spectrum), spectrum_score),
+        ("Dry lubrication".to_string(), lubrication_score),
+    ];
+    drivers.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let dominant_drivers = drivers.into_iter().filter(|d| d.1 > 0.5).take(3).map(|d| d.0).collect();
+
+    // Archard: wear depth rate = K · p · v, with K derated like the PV limit
+    let wear_depth_m  = input.wear_allowance.filter(|a| *a > 0.0).unwrap_or(DEFAULT_WEAR_DEPTH) * IN_TO_M;
+    let wear_coeff    = wear_base * 1e-9 / derate.max(1e-6);
+    let wear_rate_mps = wear_coeff * specific_load_mpa * 1e6 * sliding_velocity_mps;
+    let life_estimate_hours = (wear_rate_mps > 0.0).then(|| wear_depth_m / wear_rate_mps / 3_600.0);
+
     DutyScreenResult {
+        wear_family: mat_b.wear_family,
         load_spectrum: input.load_spectrum,
         lubrication_mode: input.lubrication_mode,
         contamination_level: input.contamination_level,
-        specific_load_psi: 0.0,
-        specific_load_mpa: 0.0,
-        sliding_velocity_mps: 0.0,
-        pv: 0.0,
-        pv_limit: 0.0,
-        pv_utilization: 0.0,
-        wear_risk: WearRisk::Low,
-        risk_score: 0.0,
-        dominant_drivers: vec![],
-        life_estimate_hours: None,
-    }
-}
-
-pub fn build_process_review(input: &BushingInput) -> ProcessReviewResult {
-    ProcessReviewResult {
-        route_id: input.process_route_id,
-        route_label: "Press Fit Only".into(),
-        tolerance_class: "IT7".into(),
-        recommended_ra_um: 1.6,
-        roundness_target_um: 5.0,
-        finish_machining_required: false,
-        thermal_assist_recommended: false,
-        assembly_thermal_assist_active: false,
-        install_force_band: InstallForceBand { low: 0.0, nominal: 0.0, high: 0.0 },
-        removal_force: 0.0,
-        notes: vec![],
-    }
-}
-
-pub fn build_approval_review(input: &BushingInput) -> ApprovalReviewResult {
-    ApprovalReviewResult {
-        standards_basis: input.standards_basis,
-        standards_revision: input.standards_revision.clone().unwrap_or_default(),
-        process_spec: input.process_spec.clone().unwrap_or_default(),
-        criticality: input.criticality,
-        approval_required: false,
-        decision: "pass".into(),
-        traceability_refs: vec![],
-        assumptions: vec![],
+        specific_load_psi,
+        specific_load_mpa,
+        sliding_velocity_mps,
+        pv,
+        pv_limit,
+        pv_utilization,
+        wear_risk: wear_risk_for(risk_score),
+        risk_score,
+        dominant_drivers,
+        life_estimate_hours,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_support::{input_with, metric, solve_with},
+        types::*,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn hot_service_reduces_interference_for_aluminum_housing() {
+        let out = solve_with(json!({ "serviceTemperatureHot": 400.0, "serviceTemperatureCold": -65.0 }));
+        let env = &out.service_envelope;
+        let get = |id: &str| env.states.iter().find(|s| s.id == id).unwrap();
+        assert!(get("hot").effective_interference < get("installed").effective_interference);
+        assert!(get("cold").effective_interference > get("installed").effective_interference);
+        assert!(get("installed").id_change_from_free < 0.0, "installed bushing ID closes");
+        assert_eq!(env.governing_state_id, "hot");
+        assert!(!out.warning_codes.iter().any(|w| w.code == BushingWarningCode::ServiceStateClearance));
+    }
+
+    #[test]
+    fn clearance_in_hot_service_is_flagged() {
+        let out = solve_with(json!({ "serviceTemperatureHot": 600.0 }));
+        let hot = out.service_envelope.states.iter().find(|s| s.id == "hot").unwrap();
+        assert_eq!(hot.fit_class, "clearance");
+        assert_eq!(hot.contact_pressure, 0.0);
+        assert!(out.warning_codes.iter().any(|w| w.code == BushingWarningCode::ServiceStateClearance));
+    }
+
+    #[test]
+    fn static_load_has_no_pv_or_life() {
+        let out = solve_with(json!({ "load": 2000.0 }));
+        assert!(out.duty_screen.specific_load_psi > 0.0);
+        assert_eq!(out.duty_screen.pv, 0.0);
+        assert!(out.duty_screen.life_estimate_hours.is_none());
+    }
+
+    #[test]
+    fn oscillating_duty_rates_pv_and_life() {
+        let base = json!({
+            "matBushing": "Bronze_C93200", "load": 3000.0, "loadSpectrum": "oscillating",
+            "oscillationAngleDeg": 30.0, "oscillationFreqHz": 2.0, "lubricationMode": "greased",
+        });
+        let greased = solve_with(base.clone()).duty_screen;
+        // 3000 lbf / (0.25 × 0.75) = 16 ksi ≈ 110 MPa; v = 0.00635 × 0.5236 × 2 ≈ 6.65 mm/s
+        assert!((greased.specific_load_psi - 16_000.0).abs() < 1e-6);
+        assert!((greased.pv - 110.316 * 0.006_650).abs() < 1e-2, "pv {}", greased.pv);
+        assert!((greased.pv_limit - 1.75 * 1.15 * 0.98).abs() < 1e-9);
+        let life_greased = greased.life_estimate_hours.unwrap();
+
+        let mut dry = base;
+        dry["lubricationMode"] = json!("dry");
+        dry["contaminationLevel"] = json!("abrasive");
+        let out = solve_with(dry);
+        assert!(out.duty_screen.pv_utilization > greased.pv_utilization);
+        assert!(out.duty_screen.life_estimate_hours.unwrap() < life_greased);
+        assert!(matches!(out.duty_screen.wear_risk, WearRisk::High | WearRisk::Severe));
+        assert!(out.warning_codes.iter().any(|w| w.code == BushingWarningCode::DutyScreenHighRisk));
+    }
+
+    #[test]
+    fn inline_bushing_wear_family_comes_from_the_record() {
+        let bronze = |family: Option<&str>| {
+            let mut m = json!({
+                "id": "Bronze_inline", "eKsi": 14_000.0, "syKsi": 20.0, "fbruKsi": 65.0, "fsuKsi": 27.0, "nu": 0.34,
+                "alphaUF": 10.0, "densityLbIn3": 0.322, "cpBtuLbF": 0.09,
+            });
+            if let Some(f) = family {
+                m["wearFamily"] = json!(f);
+            }
+            json!({
+                "matBushing": m, "load": 3000.0, "loadSpectrum": "oscillating",
+                "oscillationAngleDeg": 30.0, "oscillationFreqHz": 2.0, "lubricationMode": "greased",
+            })
+        };
+        let library = solve_with(json!({
+            "matBushing": "Bronze_C93200", "load": 3000.0, "loadSpectrum": "oscillating",
+            "oscillationAngleDeg": 30.0, "oscillationFreqHz": 2.0, "lubricationMode": "greased",
+        }));
+        let inline = solve_with(bronze(Some("bronze")));
+        assert_eq!(inline.duty_screen.wear_family, Some(WearFamily::Bronze));
+        assert_eq!(inline.duty_screen.pv_limit, library.duty_screen.pv_limit);
+        assert!(!inline.warning_codes.iter().any(|w| w.code == BushingWarningCode::WearFamilyUnknown));
+
+        // Without a family the lowest greased limit (titanium) applies, with a warning
+        let unknown = solve_with(bronze(None));
+        assert_eq!(unknown.duty_screen.wear_family, None);
+        assert!((unknown.duty_screen.pv_limit - 0.35 * 1.15 * 0.98).abs() < 1e-9);
+        assert!(unknown.warning_codes.iter().any(|w| w.code == BushingWarningCode::WearFamilyUnknown));
+    }
+
+    #[test]
+    fn hot_service_uses_derated_inline_material() {
+        let housing = |sy_curve: serde_json::Value| json!({
+            "id": "Al_hot", "eKsi": 10_300.0, "syKsi": 66.0, "fbruKsi": 140.0, "fsuKsi": 43.0, "nu": 0.33,
+            "alphaUF": 12.8, "densityLbIn3": 0.102, "cpBtuLbF": 0.23, "syCurve": sy_curve,
+        });
+        let hot = |out: &BushingOutput| out.service_envelope.states.iter().find(|s| s.id == "hot").unwrap().margin;
+        let flat    = solve_with(json!({ "matHousing": housing(json!([])), "serviceTemperatureHot": 250.0 }));
+        let derated = solve_with(json!({ "matHousing": housing(json!([[70.0, 1.0], [250.0, 0.8]])), "serviceTemperatureHot": 250.0 }));
+        assert_eq!(derated.materials.housing.source, "inline");
+        assert!(hot(&derated) < hot(&flat));
+        // Room-temperature states are unaffected by the curve
+        let installed = |out: &BushingOutput| out.service_envelope.states.iter().find(|s| s.id == "installed").unwrap().margin;
+        assert!((installed(&derated) - installed(&flat)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn metric_temperatures_convert_to_fahrenheit_reference() {
+        assert!((super::temperature_delta_f(170.0) - 100.0).abs() < 1e-9);
+        let input = input_with(metric(json!({ "serviceTemperatureHot": 120.0 })));
+        assert!((super::temperature_delta_f(input.service_temperature_hot.unwrap()) - 178.0).abs() < 1e-9);
     }
 }
@@ -0,0 +1,315 @@
+/// Inverse design: search bore (optionally snapped to a reamer catalog), wall
+/// and interference for designs that hold a target governing margin, ranked
+/// by margin and install force.
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    approval::RuleBook,
+    error::BushingError,
+    materials::MaterialLibrary,
+    math::EPS,
+    schema,
+    solver,
+    types::*,
+    units::Scale,
+};
+
+/// Most values one search axis may hold.
+pub const MAX_STEPS: usize = 1_000;
+/// Most grid points one sizing search or sweep may solve.
+pub const MAX_GRID_POINTS: usize = 100_000;
+
+fn default_max_results() -> usize {
+    10
+}
+
+/// Inclusive grid of `steps` evenly spaced values; one step yields `min`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchRange {
+    pub min: f64,
+    pub max: f64,
+    pub steps: usize,
+}
+
+impl SearchRange {
+    pub fn fixed(value: f64) -> Self {
+        Self { min: value, max: value, steps: 1 }
+    }
+
+    pub(crate) fn values(&self, name: &str) -> Result<Vec<f64>, BushingError> {
+        if self.steps == 0 || self.min > self.max || self.min.is_nan() || self.max.is_nan() {
+            return Err(BushingError::InvalidInput(format!("{name} range needs min <= max and at least one step")));
+        }
+        if self.steps > MAX_STEPS {
+            return Err(BushingError::InvalidInput(format!("{name} range has {} steps; at most {MAX_STEPS} are allowed", self.steps)));
+        }
+        if self.steps == 1 {
+            return Ok(vec![self.min]);
+        }
+        let step = (self.max - self.min) / (self.steps - 1) as f64;
+        Ok((0..self.steps).map(|i| self.min + step * i as f64).collect())
+    }
+}
+
+/// Lengths are in the base input's unit system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizingRequest {
+    pub bore: SearchRange,
+    /// Available reamer sizes; when given, only catalog bores inside `bore` are tried.
+    #[serde(default)]
+    pub reamer_catalog: Option<Vec<f64>>,
+    /// Radial wall of the straight section; ID = bore − 2·wall.
+    pub wall: SearchRange,
+    pub interference: SearchRange,
+    /// Minimum acceptable governing margin.
+    pub target_margin: f64,
+    #[serde(default = "default_max_results")]
+    pub max_results: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizingDesign {
+    pub bore_dia: f64,
+    pub id_bushing: f64,
+    pub wall: f64,
+    pub interference: f64,
+    pub governing: BushingCandidate,
+    pub install_force: f64,
+    /// 0 for designs no other feasible design beats on both margin and
+    /// install force, 1 for the next front, and so on.
+    pub pareto_rank: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizingResult {
+    pub evaluated: usize,
+    pub rejected: usize,
+    pub feasible: usize,
+    pub designs: Vec<SizingDesign>,
+    /// Highest-margin design when none meets the target.
+    pub best_infeasible: Option<SizingDesign>,
+}
+
+fn bores(req: &SizingRequest) -> Result<Vec<f64>, BushingError> {
+    let Some(catalog) = &req.reamer_catalog else {
+        return req.bore.values("bore");
+    };
+    let mut sizes: Vec<f64> = catalog
+        .iter()
+        .copied()
+        .filter(|d| *d >= req.bore.min - EPS && *d <= req.bore.max + EPS)
+        .collect();
+    sizes.sort_by(f64::total_cmp);
+    sizes.dedup_by(|a, b| (*a - *b).abs() < EPS);
+    if sizes.is_empty() {
+        return Err(BushingError::InvalidInput("no reamer catalog size falls inside the bore range".into()));
+    }
+    Ok(sizes)
+}
+
+/// Reject a grid whose axis lengths multiply past [`MAX_GRID_POINTS`].
+pub(crate) fn check_grid(axes: &[usize]) -> Result<(), BushingError> {
+    let points = axes.iter().try_fold(1usize, |n, &len| n.checked_mul(len)).unwrap_or(usize::MAX);
+    if points > MAX_GRID_POINTS {
+        return Err(BushingError::InvalidInput(format!("grid has {points} points; at most {MAX_GRID_POINTS} are allowed")));
+    }
+    Ok(())
+}
+
+/// Move the bore to `bore`; an entered limit band moves with the nominal.
+pub(crate) fn set_bore(input: &mut BushingInput, bore: f64) {
+    let shift = bore - input.bore_nominal.unwrap_or(input.bore_dia);
+    input.bore_dia     = bore;
+    input.bore_nominal = Some(bore);
+    input.bore_lower   = input.bore_lower.map(|x| x + shift);
+    input.bore_upper   = input.bore_upper.map(|x| x + shift);
+}
+
+/// Move the interference to `delta`; an entered limit band moves with the nominal.
+pub(crate) fn set_interference(input: &mut BushingInput, delta: f64) {
+    let shift = delta - input.interference_nominal.unwrap_or(input.interference);
+    input.interference         = delta;
+    input.interference_nominal = Some(delta);
+    input.interference_lower   = input.interference_lower.map(|x| x + shift);
+    input.interference_upper   = input.interference_upper.map(|x| x + shift);
+}
+
+fn with_design(base: &BushingInput, bore: f64, id: f64, interference: f64) -> BushingInput {
+    let mut input = base.clone();
+    set_bore(&mut input, bore);
+    set_interference(&mut input, interference);
+    input.id_bushing = id;
+    input
+}
+
+/// A design is feasible when it meets the target margin with no error-severity warnings.
+fn feasible(out: &BushingOutput, target: f64) -> bool {
+    out.governing.margin >= target && !out.warning_codes.iter().any(|w| w.severity == WarningSeverity::Error)
+}
+
+fn assign_pareto_ranks(designs: &mut [SizingDesign]) {
+    let dominates = |a: &SizingDesign, b: &SizingDesign| {
+        a.governing.margin >= b.governing.margin
+            && a.install_force <= b.install_force
+            && (a.governing.margin > b.governing.margin || a.install_force < b.install_force)
+    };
+    let mut remaining: Vec<usize> = (0..designs.len()).collect();
+    let mut rank = 0;
+    while !remaining.is_empty() {
+        let front: Vec<usize> = remaining
+            .iter()
+            .copied()
+            .filter(|&i| !remaining.iter().any(|&j| dominates(&designs[j], &designs[i])))
+            .collect();
+        for &i in &front {
+            designs[i].pareto_rank = rank;
+        }
+        remaining.retain(|i| !front.contains(i));
+        rank += 1;
+    }
+}
+
+/// Search the grid around `base` (materials, housing, process and load
+/// inputs) and return the best designs that hold `target_margin`, solving
+/// against `rules` with materials from `library`. Designs are reported in
+/// the base input's unit system, like the solved outputs they carry.
+pub fn optimize(base: &BushingInput, req: &SizingRequest, rules: &RuleBook, library: &MaterialLibrary) -> Result<SizingResult, BushingError> {
+    let bores         = bores(req)?;
+    let walls         = req.wall.values("wall")?;
+    let interferences = req.interference.values("interference")?;
+    check_grid(&[bores.len(), walls.len(), interferences.len()])?;
+    let to_in         = Scale::to_internal(base.units).length;
+
+    let (mut evaluated, mut rejected) = (0, 0);
+    let mut feasible_designs = vec![];
+    let mut best_infeasible: Option<SizingDesign> = None;
+
+    for &bore in &bores {
+        for &wall in &walls {
+            for &interference in &interferences {
+                let id    = bore - 2.0 * wall;
+                let input = with_design(base, bore * to_in, id * to_in, interference * to_in);
+                if id <= 0.0 || schema::validate(&input).is_err() {
+                    rejected += 1;
+                    continue;
+                }
+                evaluated += 1;
+                let out    = solver::compute_with(&input, rules, library)?;
+                let design = SizingDesign {
+                    bore_dia: bore,
+                    id_bushing: id,
+                    wall,
+                    interference,
+                    governing: out.governing.clone(),
+                    install_force: out.physics.install_force,
+                    pareto_rank: 0,
+                };
+                if feasible(&out, req.target_margin) {
+                    feasible_designs.push(design);
+                } else if best_infeasible.as_ref().is_none_or(|b| design.governing.margin > b.governing.margin) {
+                    best_infeasible = Some(design);
+                }
+            }
+        }
+    }
+
+    assign_pareto_ranks(&mut feasible_designs);
+    feasible_designs.sort_by(|a, b| {
+        a.pareto_rank
+            .cmp(&b.pareto_rank)
+            .then(b.governing.margin.total_cmp(&a.governing.margin))
+            .then(a.install_force.total_cmp(&b.install_force))
+    });
+    let feasible = feasible_designs.len();
+    feasible_designs.truncate(req.max_results);
+
+    Ok(SizingResult {
+        evaluated,
+        rejected,
+        feasible,
+        designs: feasible_designs,
+        best_infeasible: if feasible == 0 { best_infeasible } else { None },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{input_with, metric, raw_with};
+    use serde_json::json;
+
+    fn run(req: &SizingRequest) -> Result<SizingResult, BushingError> {
+        optimize(&input_with(json!({})), req, RuleBook::builtin(), MaterialLibrary::builtin())
+    }
+
+    fn request() -> SizingRequest {
+        SizingRequest {
+            bore: SearchRange { min: 0.49, max: 0.51, steps: 3 },
+            reamer_catalog: None,
+            wall: SearchRange { min: 0.05, max: 0.15, steps: 3 },
+            interference: SearchRange { min: 0.0005, max: 0.0025, steps: 5 },
+            target_margin: 0.2,
+            max_results: 5,
+        }
+    }
+
+    #[test]
+    fn ranked_feasible_designs_meet_target() {
+        let res = run(&request()).unwrap();
+        assert_eq!(res.evaluated + res.rejected, 45);
+        assert!(res.feasible > 0 && res.designs.len() <= 5);
+        assert!(res.designs.iter().all(|d| d.governing.margin >= 0.2));
+        assert_eq!(res.designs[0].pareto_rank, 0);
+        assert!(res.designs.windows(2).all(|w| w[0].pareto_rank <= w[1].pareto_rank));
+        // The front trades margin against install force
+        let front: Vec<_> = res.designs.iter().filter(|d| d.pareto_rank == 0).collect();
+        assert!(front.windows(2).all(|w| w[0].governing.margin >= w[1].governing.margin));
+    }
+
+    #[test]
+    fn oversized_grids_are_rejected() {
+        let too_many = SizingRequest { bore: SearchRange { min: 0.4, max: 0.6, steps: MAX_STEPS + 1 }, ..request() };
+        assert!(matches!(run(&too_many), Err(BushingError::InvalidInput(m)) if m.contains("bore range")));
+
+        let axis  = SearchRange { min: 0.0005, max: 0.0025, steps: MAX_STEPS };
+        let cube  = SizingRequest { wall: SearchRange { min: 0.05, max: 0.15, steps: MAX_STEPS }, interference: axis, ..request() };
+        assert!(matches!(run(&cube), Err(BushingError::InvalidInput(m)) if m.contains("grid has")));
+    }
+
+    #[test]
+    fn reamer_catalog_snaps_bores() {
+        let req = SizingRequest { reamer_catalog: Some(vec![0.4995, 0.5005, 0.625]), ..request() };
+        let res = run(&req).unwrap();
+        assert!(res.designs.iter().all(|d| d.bore_dia == 0.4995 || d.bore_dia == 0.5005));
+        let empty = SizingRequest { reamer_catalog: Some(vec![0.75]), ..request() };
+        assert!(matches!(run(&empty), Err(BushingError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn invalid_base_is_rejected_before_the_search() {
+        let err = crate::size_bushing(raw_with(json!({ "edgeDist": 0.2 })), &request()).unwrap_err();
+        assert!(err.issues().iter().any(|i| i.pointer == "/edgeDist"), "{err}");
+    }
+
+    #[test]
+    fn metric_request_is_searched_and_reported_in_mm() {
+        let metric = input_with(metric(json!({})));
+        let inch = request();
+        let mm   = |r: &SearchRange| SearchRange { min: r.min * 25.4, max: r.max * 25.4, steps: r.steps };
+        let req  = SizingRequest { bore: mm(&inch.bore), wall: mm(&inch.wall), interference: mm(&inch.interference), ..request() };
+        let res  = optimize(&metric, &req, RuleBook::builtin(), MaterialLibrary::builtin()).unwrap();
+        let reference = run(&inch).unwrap();
+        assert_eq!((res.evaluated, res.feasible), (reference.evaluated, reference.feasible));
+        let (d, r) = (&res.designs[0], &reference.designs[0]);
+        assert!((d.bore_dia - r.bore_dia * 25.4).abs() < 1e-9 && (d.id_bushing - r.id_bushing * 25.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn unreachable_target_reports_best_attempt() {
+        let req = SizingRequest { target_margin: 50.0, ..request() };
+        let res = run(&req).unwrap();
+        assert_eq!(res.feasible, 0);
+        assert!(res.designs.is_empty());
+        assert!(res.best_infeasible.is_some());
+    }
+}
@@ -1,177 +1,346 @@
-/// Core bushing state machine.
-/// Stub — full port from solveEngine.ts::computeState() is Task #9.
+/// Core bushing solve: measured overrides, materials, fit and stresses, reviews, warnings, then unit conversion.
 use crate::{
+    approval::{self, RuleBook},
+    axial::{self, AxialModel},
+    bearing,
+    candidates,
+    countersink,
+    criteria,
     error::BushingError,
-    materials,
+    fit_band,
+    flange,
+    housing::{self, EquivalentHousing},
+    ligament,
+    materials::{self, MaterialLibrary},
     math,
+    measured,
+    process,
+    schema,
     service,
+    thermal,
+    tolerance,
     types::*,
+    units,
 };
 
 pub fn compute(input: &BushingInput) -> Result<BushingOutput, BushingError> {
-    let mat_h = materials::lookup(&input.mat_housing)
-        .ok_or_else(|| BushingError::InvalidInput(format!("unknown housing material: {}", input.mat_housing)))?;
-    let mat_b = materials::lookup(&input.mat_bushing)
-        .ok_or_else(|| BushingError::InvalidInput(format!("unknown bushing material: {}", input.mat_bushing)))?;
+    compute_with_rules(input, RuleBook::builtin())
+}
 
-    // Geometry
-    let bore_r  = input.bore_dia / 2.0;
-    let id_r    = input.id_bushing / 2.0;
-    let wall    = bore_r - id_r;
+/// Solve with the approval review evaluated against `rules`.
+pub fn compute_with_rules(input: &BushingInput, rules: &RuleBook) -> Result<BushingOutput, BushingError> {
+    compute_with(input, rules, MaterialLibrary::builtin())
+}
 
-    // Thermal delta (sign: housing expands → reduces interference)
-    let dt = input.d_t;
-    let delta_thermal = dt * (mat_h.alpha_u_f - mat_b.alpha_u_f) * input.bore_dia * 1e-6;
+/// Resolve a material and apply the input's allowable basis.
+fn resolve_material(
+    input: &BushingInput,
+    library: &MaterialLibrary,
+    id: &str,
+    part: &str,
+) -> Result<(MaterialProps, MaterialSummary), BushingError> {
+    let (m, source) = materials::resolve(input, library, id)
+        .ok_or_else(|| BushingError::InvalidInput(format!("unknown {part} material: {id}")))?;
+    let (m, basis_applied) = materials::with_basis(&m, input.material_basis);
+    let summary = MaterialSummary {
+        id: m.id.clone(),
+        name: m.name.clone(),
+        source: source.into(),
+        basis: input.material_basis,
+        basis_applied,
+    };
+    Ok((m, summary))
+}
 
-    // Effective interference
-    let delta_total = input.interference - delta_thermal;
+/// Solve with the approval review evaluated against `rules`, taking materials
+/// from `library` unless the input defines them inline.
+pub fn compute_with(input: &BushingInput, rules: &RuleBook, library: &MaterialLibrary) -> Result<BushingOutput, BushingError> {
+    // As-built measurements replace nominal geometry and must still be valid
+    let measured = measured::apply(input);
+    if measured.active {
+        schema::validate(&measured.input)?;
+    }
+    let input = &measured.input;
 
-    // Effective housing OD (use housing_width as surrogate in simplified model)
-    let od_housing = input.housing_width;
+    let (mat_h_rt, housing_material) = resolve_material(input, library, &input.mat_housing, "housing")?;
+    let (mat_b_rt, bushing_material) = resolve_material(input, library, &input.mat_bushing, "bushing")?;
+    // Properties at the analysis temperature; service states derate from room temperature
+    let analysis_temp = service::REFERENCE_TEMP_F + input.d_t;
+    let mat_h = materials::at_temperature(&mat_h_rt, analysis_temp);
+    let mat_b = materials::at_temperature(&mat_b_rt, analysis_temp);
 
-    // Contact pressure (Lame composite)
-    let pressure = math::lame_contact_pressure(
-        delta_total,
-        input.bore_dia,
-        input.id_bushing,
-        od_housing,
-        mat_b.e_ksi,
-        mat_b.nu,
-        mat_h.e_ksi,
-        mat_h.nu,
-    );
+    // Tolerance stack — the solve runs at the resolved bore / achieved interference nominals
+    let mut tolerance = tolerance::build_tolerance(input);
+    let bore_dia  = tolerance.bore.nominal;
+    let delta_user = tolerance.achieved_interference.nominal;
+
+    // Geometry
+    let bore_r  = bore_dia / 2.0;
+    let id_r    = input.id_bushing / 2.0;
+    let wall    = bore_r - id_r;
+
+    let mut point = solve_point(input, &mat_h, &mat_b, bore_dia, delta_user, input.d_t);
+    let FitPoint { delta_thermal, delta_total, pressure, od_housing, axial, housing: eq, .. } = point;
 
-    // Stress field — 21 sample points
+    // Stress field — 21 sample points; the bushing sees the contact pressure externally
     let n_samples = 21;
-    let bushing_samples: Vec<StressSample> = math::build_lame_field(id_r, bore_r, pressure, n_samples)
+    let sample = |nu: f64| {
+        move |(r, sigma_r, sigma_theta): (f64, f64, f64)| StressSample {
+            r,
+            sigma_r,
+            sigma_theta,
+            sigma_axial: axial.sigma_axial(nu, sigma_r, sigma_theta),
+        }
+    };
+    let bushing_samples: Vec<StressSample> = math::build_lame_field_external(id_r, bore_r, pressure, n_samples)
         .into_iter()
-        .map(|(r, sigma_r, sigma_theta)| StressSample { r, sigma_r, sigma_theta, sigma_axial: 0.0 })
+        .map(sample(mat_b.nu))
         .collect();
     let housing_inner_r = bore_r;
     let housing_outer_r = od_housing / 2.0;
     let housing_samples: Vec<StressSample> = math::build_lame_field(housing_inner_r, housing_outer_r, pressure, n_samples)
         .into_iter()
-        .map(|(r, sigma_r, sigma_theta)| StressSample { r, sigma_r, sigma_theta, sigma_axial: 0.0 })
+        .map(sample(mat_h.nu))
         .collect();
 
     let bushing_boundary = boundary_from_samples(&bushing_samples);
     let housing_boundary = boundary_from_samples(&housing_samples);
 
-    // Hoop margins
-    let sigma_hoop_b = bushing_samples.first().map(|s| s.sigma_theta.abs()).unwrap_or(0.0);
-    let sigma_hoop_h = housing_samples.first().map(|s| s.sigma_theta.abs()).unwrap_or(0.0);
-    let ms_b = if sigma_hoop_b > 0.0 { mat_b.sy_ksi / sigma_hoop_b - 1.0 } else { 999.0 };
-    let ms_h = if sigma_hoop_h > 0.0 { mat_h.sy_ksi / sigma_hoop_h - 1.0 } else { 999.0 };
-
-    // Edge distance (simplified)
-    let ed_min_seq  = 1.5 * input.bore_dia;
-    let ed_min_str  = 2.0 * input.bore_dia;
-    let governing_ed = if input.edge_dist >= ed_min_str { "strength" } else { "sequencing" };
-
-    let governing = if ms_b < ms_h {
-        BushingCandidate { name: "bushing_hoop".into(), margin: ms_b }
-    } else {
-        BushingCandidate { name: "housing_hoop".into(), margin: ms_h }
+    // Edge distance: flat sequencing rule against the coupled ligament requirement
+    let ed_min_seq   = 1.5 * bore_dia;
+    let ed_min_str   = point.physics.ed_min_coupled;
+    let governing_ed = match ed_min_str {
+        Some(s) if ed_min_seq >= s => "sequencing",
+        _                          => "strength",
     };
 
-    let od_bushing = input.bore_dia;
+    let od_bushing = tolerance.od_bushing.nominal;
     let sleeve_wall = wall;
-    let neck_wall: Option<f64> = None;
 
-    let si = service::ServiceInputs { input, pressure, od_bushing };
+    // Countersinks and the profile wall they leave
+    let countersinks = countersink::solve(input, &tolerance.od_bushing);
+    if let Some(cs) = &countersinks.internal {
+        tolerance.cs_internal_dia   = Some(cs.dia_tol.clone());
+        tolerance.cs_internal_depth = Some(cs.depth_tol.clone());
+    }
+    if let Some(cs) = &countersinks.external {
+        tolerance.cs_external_dia   = Some(cs.dia_tol.clone());
+        tolerance.cs_external_depth = Some(cs.depth_tol.clone());
+    }
+    let profiled  = input.bushing_type != BushingType::Straight || countersinks.internal.is_some();
+    let neck_wall = profiled.then(|| countersink::minimum_wall(input, od_bushing, &countersinks));
+    let cs_solved = CsSolved {
+        id: countersinks.internal.as_ref().map(|c| c.dim.clone()),
+        od: countersinks.external.as_ref().map(|c| c.dim.clone()),
+    };
+
+    let si = service::ServiceInputs { input, mat_h: &mat_h_rt, mat_b: &mat_b_rt, bore_dia, delta_user, pressure, od_bushing };
     let service_envelope = service::build_service_envelope(&si);
-    let duty_screen      = service::build_duty_screen(input);
-    let process          = service::build_process_review(input);
-    let review           = service::build_approval_review(input);
+    let duty_screen      = service::build_duty_screen(input, &mat_b_rt);
+    let thermal_plan     = thermal::build_thermal_plan(&si);
+    thermal::apply_install_state(&si, &thermal_plan, &mut point.physics);
+    let process          = process::build_process_review(&si, &point.physics, thermal_plan);
+    let flange           = flange::build_flange(input, &mat_h, &mat_b, bore_dia, od_bushing, neck_wall.unwrap_or(sleeve_wall), point.physics.install_force);
+    let bearing          = bearing::build_bearing(input, &mat_h, &mat_b, bore_dia, pressure, point.hoop.housing_sigma);
 
     let lame = LameResult {
         model: "Lame thick-wall cylinder".into(),
         delta_total,
         delta_thermal,
-        delta_user: input.interference,
-        bore_dia: input.bore_dia,
+        delta_user,
+        bore_dia,
         id_bushing: input.id_bushing,
         effective_od_housing: od_housing,
-        d_equivalent: input.bore_dia,
-        psi: 0.0,
-        lambda: 0.0,
-        w_eff: 0.0,
-        e_eff: 0.0,
-        term_b: 0.0,
-        term_h: 0.0,
-        pressure_psi: pressure,
-        pressure_ksi: pressure / 1_000.0,
+        d_equivalent: eq.d_equivalent,
+        psi: eq.psi,
+        lambda: eq.lambda,
+        w_eff: eq.w_eff,
+        e_eff: eq.e_eff,
+        term_b: point.term_b,
+        term_h: point.term_h,
+        pressure_psi: pressure * 1_000.0,
+        pressure_ksi: pressure,
         field: LameField {
             sign_convention: "tension positive".into(),
-            axial_model: "plane_stress".into(),
+            axial_model: axial.label().into(),
             bushing: StressRegion { inner_radius: id_r, outer_radius: bore_r, samples: bushing_samples, boundary: bushing_boundary },
             housing: StressRegion { inner_radius: housing_inner_r, outer_radius: housing_outer_r, samples: housing_samples, boundary: housing_boundary },
         },
     };
 
-    let tolerance = build_tolerance_stub(input);
-    let warnings  = build_warnings(input, pressure, ms_b, ms_h, input.edge_dist, ed_min_seq, ed_min_str);
+    let criteria = criteria::build_criteria(input, &mat_h, &mat_b, &lame.field);
+    let fit_band = fit_band::build_fit_band(input, &tolerance, &mat_h_rt, &mat_b_rt);
 
-    Ok(BushingOutput {
+    let mut out = BushingOutput {
+        units: units::labels(UnitSystem::Imperial),
         sleeve_wall,
         neck_wall,
         od_installed: od_bushing,
-        cs_solved: CsSolved { id: None, od: None },
+        cs_solved: cs_solved.clone(),
         pressure,
         lame,
-        hoop: HoopResult {
-            housing_sigma: sigma_hoop_h,
-            housing_ms: ms_h,
-            bushing_sigma: sigma_hoop_b,
-            bushing_ms: ms_b,
-            ligament_sigma: 0.0,
-            ligament_ms: 999.0,
-            ed_required_ligament: None,
-        },
+        hoop: point.hoop,
+        criteria,
         edge_distance: EdgeDistanceResult {
             ed_min_sequence: ed_min_seq,
             ed_min_strength: ed_min_str,
             ed_actual: input.edge_dist,
             governing: governing_ed.into(),
         },
-        governing,
+        governing: BushingCandidate::default(),
+        physics: point.physics,
+        geometry: GeometryResult {
+            od_bushing,
+            wall_straight: sleeve_wall,
+            wall_neck: neck_wall.unwrap_or(sleeve_wall),
+            cs_internal: cs_solved.id.unwrap_or(CsSolvedDim { dia: input.cs_dia, depth: input.cs_depth, angle_deg: input.cs_angle }),
+            cs_external: cs_solved.od.unwrap_or(CsSolvedDim { dia: input.ext_cs_dia, depth: input.ext_cs_depth, angle_deg: input.ext_cs_angle }),
+            is_saturation_active: eq.saturated,
+        },
+        flange,
+        bearing,
+        service_envelope,
+        duty_screen,
+        process,
+        review: ApprovalReviewResult::default(),
+        input_basis: measured.basis,
+        materials: MaterialsUsed { housing: housing_material, bushing: bushing_material },
+        measured_part_summary: measured.summary,
+        tolerance,
+        fit_band,
+        candidates: vec![],
+        warning_codes: vec![],
+        warnings: vec![],
+        effective_input: None,
+    };
+    out.candidates = candidates::collect(input, &out);
+    out.governing  = candidates::governing(&out.candidates);
+
+    let warnings = build_warnings(input, &out);
+    out.warning_codes = warnings.codes;
+    out.warnings      = warnings.msgs;
+
+    // Approval rules see the warnings above, then add their own
+    out.review = approval::build_approval_review(input, &out, rules);
+    if let Some(w) = approval::review_warning(&out.review) {
+        out.warnings.push(w.message.clone());
+        out.warning_codes.push(w);
+    }
+
+    // Solved in internal units; messages above already read in the input's system
+    units::to_system(&mut out, input.units);
+    Ok(out)
+}
+
+/// Fit evaluated at a single bore / interference / temperature point.
+pub(crate) struct FitPoint {
+    pub delta_thermal: f64,
+    pub delta_total: f64,
+    pub pressure: f64,
+    pub od_housing: f64,
+    pub housing: EquivalentHousing,
+    pub term_b: f64,
+    pub term_h: f64,
+    pub axial: AxialModel,
+    pub hoop: HoopResult,
+    pub physics: PhysicsResult,
+}
+
+/// Solve contact pressure, hoop stresses and margins for one point of the fit.
+/// `delta_user` is the diametral interference before thermal correction.
+pub(crate) fn solve_point(
+    input: &BushingInput,
+    mat_h: &MaterialProps,
+    mat_b: &MaterialProps,
+    bore_dia: f64,
+    delta_user: f64,
+    d_t: f64,
+) -> FitPoint {
+    let bore_r = bore_dia / 2.0;
+    let id_r   = input.id_bushing / 2.0;
+
+    // Thermal delta (sign: housing expands → reduces interference)
+    let delta_thermal = d_t * (mat_h.alpha_u_f - mat_b.alpha_u_f) * bore_dia * 1e-6;
+
+    // Effective interference
+    let delta_total = delta_user - delta_thermal;
+
+    // Area-equivalent housing OD for the lug / plate around the hole
+    let housing    = housing::equivalent_housing(input, bore_dia);
+    let od_housing = housing.d_equivalent;
+
+    // Contact pressure (Lame composite, housing compliance scaled by psi)
+    let (term_b, term_h) = math::lame_compliance_terms(
+        bore_dia,
+        input.id_bushing,
+        od_housing,
+        mat_b.e_ksi,
+        mat_b.nu,
+        mat_h.e_ksi,
+        mat_h.nu,
+        housing.psi,
+    );
+    let pressure = if delta_total > 0.0 { delta_total / (term_b + term_h) } else { 0.0 };
+
+    // Hoop stresses at the inner wall of each region
+    let sigma_hoop_b = math::lame_hoop_stress(id_r, bore_r, pressure, id_r).abs();
+    let sigma_hoop_h = math::lame_hoop_stress(bore_r, od_housing / 2.0, pressure, bore_r).abs();
+
+    // Axial stress from end constraint; margins on the selected criterion and allowable basis
+    let axial      = AxialModel::new(input.end_constraint, input.housing_len, bore_r - id_r);
+    let surface_h  = axial::housing_surface(&axial, mat_h.nu, bore_r, od_housing / 2.0, pressure);
+    let surface_b  = axial::bushing_surface(&axial, mat_b.nu, id_r, bore_r, pressure);
+    let sigma_eq_h = surface_h.equivalent(input.failure_criterion);
+    let sigma_eq_b = surface_b.equivalent(input.failure_criterion);
+    let ff         = criteria::fitting_factor(input);
+    let ms_b = criteria::margin(criteria::allowable(input.allowable_basis, mat_b, ff), sigma_eq_b);
+    let ms_h = criteria::margin(criteria::allowable(input.allowable_basis, mat_h, ff), sigma_eq_h);
+
+    // Net section between the bore and the part edge
+    let lig = ligament::evaluate(input, mat_h, bore_dia, pressure);
+
+    // Axial press / push-out force over the engaged length
+    let retained_force = process::press_force(input.friction, pressure, bore_dia, input.housing_len);
+
+    FitPoint {
+        delta_thermal,
+        delta_total,
+        pressure,
+        od_housing,
+        housing,
+        term_b,
+        term_h,
+        axial,
+        hoop: HoopResult {
+            housing_sigma: sigma_hoop_h,
+            housing_ms: ms_h,
+            bushing_sigma: sigma_hoop_b,
+            bushing_ms: ms_b,
+            housing_sigma_eq: sigma_eq_h,
+            bushing_sigma_eq: sigma_eq_b,
+            ligament_sigma: lig.sigma,
+            ligament_ms: lig.ms,
+            ed_required_ligament: lig.ed_required,
+        },
         physics: PhysicsResult {
             delta_effective: delta_total,
             install_delta_effective: delta_total,
             contact_pressure: pressure,
             install_contact_pressure: pressure,
-            install_force: 0.0,
-            retained_install_force: 0.0,
-            assembly_thermal_delta: delta_thermal,
+            install_force: retained_force,
+            retained_install_force: retained_force,
+            assembly_thermal_delta: 0.0,
             stress_hoop_housing: sigma_hoop_h,
             stress_hoop_bushing: sigma_hoop_b,
             margin_housing: ms_h,
             margin_bushing: ms_b,
-            stress_axial_housing: 0.0,
-            stress_axial_bushing: 0.0,
-            axial_constraint_factor: 0.0,
-            axial_length_factor: 1.0,
-            ed_min_coupled: ed_min_str,
-        },
-        geometry: GeometryResult {
-            od_bushing,
-            wall_straight: sleeve_wall,
-            wall_neck: 0.0,
-            cs_internal: CsSolvedDim { dia: input.cs_dia, depth: input.cs_depth, angle_deg: input.cs_angle },
-            cs_external: CsSolvedDim { dia: input.ext_cs_dia, depth: input.ext_cs_depth, angle_deg: input.ext_cs_angle },
-            is_saturation_active: false,
+            stress_axial_housing: surface_h.sigma_axial,
+            stress_axial_bushing: surface_b.sigma_axial,
+            axial_constraint_factor: axial.constraint_factor,
+            axial_length_factor: axial.length_factor,
+            ed_min_coupled: lig.ed_required,
         },
-        service_envelope,
-        duty_screen,
-        process,
-        review,
-        input_basis: InputBasis { bore: "nominal".into(), id: "nominal".into(), edge_dist: "nominal".into(), housing_width: "nominal".into() },
-        measured_part_summary: MeasuredPartSummary { applied: false, basis: "nominal".into(), overrides: vec![], notes: vec![] },
-        tolerance,
-        candidates: vec![],
-        warning_codes: warnings.0,
-        warnings: warnings.1,
-    })
+    }
 }
 
 fn boundary_from_samples(samples: &[StressSample]) -> StressBoundary {
@@ -198,165 +367,164 @@ fn boundary_from_samples(samples: &[StressSample]) -> StressBoundary {
     }
 }
 
-fn build_tolerance_stub(input: &BushingInput) -> ToleranceResult {
-    use crate::math::make_range;
-    let bore_r   = make_range(input.bore_dia - 0.001, input.bore_dia + 0.001);
-    let int_r    = make_range(input.interference - 0.0005, input.interference + 0.0005);
-    let od_r     = make_range(input.bore_dia - 0.001, input.bore_dia + 0.001);
-    let achiev_r = make_range(input.interference - 0.0005, input.interference + 0.0005);
-    ToleranceResult {
-        status: "ok".into(),
-        notes: vec![],
-        enforcement: ToleranceEnforcement {
-            enabled: input.enforce_interference_tolerance,
-            satisfied: true,
-            blocked: false,
-            reason_codes: vec![],
-            required_bore_tol_width: 0.0,
-            available_bore_tol_width: 0.0,
-            target_interference_width: 0.0,
-            lower_violation: 0.0,
-            upper_violation: 0.0,
-            bore_nominal_shift_applied: 0.0,
-        },
-        bore: bore_r,
-        interference_target: int_r,
-        od_bushing: od_r,
-        achieved_interference: achiev_r,
-        cs_internal_dia: None,
-        cs_internal_depth: None,
-        cs_external_dia: None,
-        cs_external_depth: None,
+/// Coded warnings collected alongside their plain-text messages.
+#[derive(Default)]
+struct Warnings {
+    codes: Vec<BushingWarning>,
+    msgs: Vec<String>,
+}
+
+impl Warnings {
+    fn push(&mut self, code: BushingWarningCode, severity: WarningSeverity, message: impl Into<String>) {
+        let message = message.into();
+        self.msgs.push(message.clone());
+        self.codes.push(BushingWarning { code, message, severity });
     }
 }
 
-fn build_warnings(
-    input: &BushingInput,
-    _pressure: f64,
-    ms_b: f64,
-    ms_h: f64,
-    ed_actual: f64,
-    ed_seq: f64,
-    ed_str: f64,
-) -> (Vec<BushingWarning>, Vec<String>) {
-    let mut codes = vec![];
-    let mut msgs  = vec![];
+/// Derive warnings from the fully-assembled output.
+fn build_warnings(input: &BushingInput, out: &BushingOutput) -> Warnings {
+    let mut w = Warnings::default();
+    let show  = units::Show::new(input.units);
+
+    // Non-blocking validation findings (blocking ones never reach the solver)
+    for issue in schema::check(input).into_iter().filter(|i| i.severity != WarningSeverity::Error) {
+        w.push(issue.code, issue.severity, issue.message);
+    }
 
     if input.id_bushing >= input.bore_dia {
-        codes.push(BushingWarning { code: BushingWarningCode::BushingIdGeBore, message: "Bushing ID ≥ bore diameter.".into(), severity: WarningSeverity::Error });
-        msgs.push("Bushing ID ≥ bore diameter.".into());
+        w.push(BushingWarningCode::BushingIdGeBore, WarningSeverity::Error, "Bushing ID ≥ bore diameter.");
+    }
+    if out.sleeve_wall < input.min_wall_straight {
+        w.push(
+            BushingWarningCode::StraightWallBelowMin,
+            WarningSeverity::Warning,
+            format!("Wall {} < min {}", show.length(out.sleeve_wall, 4), show.length(input.min_wall_straight, 4)),
+        );
+    }
+    if let Some(neck) = out.neck_wall.filter(|n| *n < input.min_wall_neck) {
+        w.push(
+            BushingWarningCode::NeckWallBelowMin,
+            WarningSeverity::Error,
+            format!("Neck wall {} < min {}", show.length(neck, 4), show.length(input.min_wall_neck, 4)),
+        );
     }
-    let sleeve_wall = (input.bore_dia - input.id_bushing) / 2.0;
-    if sleeve_wall < input.min_wall_straight {
-        codes.push(BushingWarning { code: BushingWarningCode::StraightWallBelowMin, message: format!("Wall {:.4} < min {:.4}", sleeve_wall, input.min_wall_straight), severity: WarningSeverity::Warning });
-        msgs.push(format!("Wall {sleeve_wall:.4} < min {:.4}", input.min_wall_straight));
+    for cs in countersink::warnings(input, out) {
+        w.push(cs.code, cs.severity, cs.message);
     }
-    if ms_b < 0.0 || ms_h < 0.0 {
-        codes.push(BushingWarning { code: BushingWarningCode::NetClearanceFit, message: "Negative margin of safety.".into(), severity: WarningSeverity::Error });
-        msgs.push("Negative margin of safety.".into());
+    for m in [&out.materials.housing, &out.materials.bushing].into_iter().filter(|m| !m.basis_applied) {
+        let basis = if m.basis == MaterialBasis::A { "A" } else { "B" };
+        w.push(
+            BushingWarningCode::MaterialBasisUnavailable,
+            WarningSeverity::Warning,
+            format!("{basis}-basis allowables not published for {}; base values used.", m.name),
+        );
     }
-    if ed_actual < ed_seq {
-        codes.push(BushingWarning { code: BushingWarningCode::EdgeDistanceSequenceFail, message: format!("Edge distance {ed_actual:.4} < sequencing min {ed_seq:.4}"), severity: WarningSeverity::Warning });
-        msgs.push(format!("Edge distance {ed_actual:.4} < sequencing min {ed_seq:.4}"));
-    } else if ed_actual < ed_str {
-        codes.push(BushingWarning { code: BushingWarningCode::EdgeDistanceStrengthFail, message: format!("Edge distance {ed_actual:.4} < strength min {ed_str:.4}"), severity: WarningSeverity::Warning });
-        msgs.push(format!("Edge distance {ed_actual:.4} < strength min {ed_str:.4}"));
+    if out.hoop.bushing_ms < 0.0 || out.hoop.housing_ms < 0.0 {
+        w.push(BushingWarningCode::NetClearanceFit, WarningSeverity::Error, "Negative margin of safety.");
+    }
+    let ed = &out.edge_distance;
+    if ed.ed_actual < ed.ed_min_sequence {
+        w.push(
+            BushingWarningCode::EdgeDistanceSequenceFail,
+            WarningSeverity::Warning,
+            format!("Edge distance {} < sequencing min {}", show.length(ed.ed_actual, 4), show.length(ed.ed_min_sequence, 4)),
+        );
+    }
+    match ed.ed_min_strength {
+        None => w.push(
+            BushingWarningCode::EdgeDistanceStrengthFail,
+            WarningSeverity::Error,
+            "No edge distance satisfies the ligament; the fit pressure alone exceeds the housing allowable.",
+        ),
+        Some(min) if ed.ed_actual < min && ed.ed_actual >= ed.ed_min_sequence => w.push(
+            BushingWarningCode::EdgeDistanceStrengthFail,
+            WarningSeverity::Warning,
+            format!("Edge distance {} < strength min {}", show.length(ed.ed_actual, 4), show.length(min, 4)),
+        ),
+        Some(_) => {}
+    }
+    if out.tolerance.status == "infeasible" {
+        w.push(
+            BushingWarningCode::ToleranceInfeasible,
+            WarningSeverity::Warning,
+            "Bore/interference tolerance bands are incompatible for full-range containment.",
+        );
+    }
+    if out.tolerance.enforcement.blocked {
+        w.push(
+            BushingWarningCode::InterferenceEnforcementBlocked,
+            WarningSeverity::Error,
+            "Interference enforcement is enabled but full containment could not be satisfied under current constraints.",
+        );
+    }
+    let clearance: Vec<&str> = out
+        .service_envelope
+        .states
+        .iter()
+        .filter(|st| st.id != "free" && st.fit_class == "clearance")
+        .map(|st| st.label.as_str())
+        .collect();
+    if !clearance.is_empty() {
+        w.push(
+            BushingWarningCode::ServiceStateClearance,
+            WarningSeverity::Warning,
+            format!("Fit loses interference in service state(s): {}. Review thermal and wear allowances.", clearance.join(", ")),
+        );
+    }
+    let duty = &out.duty_screen;
+    if duty.wear_family.is_none() && duty.sliding_velocity_mps > 0.0 {
+        w.push(
+            BushingWarningCode::WearFamilyUnknown,
+            WarningSeverity::Warning,
+            format!(
+                "Bushing material {} has no wear family; the most conservative PV and wear limits were used.",
+                out.materials.bushing.name
+            ),
+        );
+    }
+    if matches!(duty.wear_risk, WearRisk::High | WearRisk::Severe) || duty.pv_utilization > 0.85 {
+        w.push(
+            BushingWarningCode::DutyScreenHighRisk,
+            WarningSeverity::Warning,
+            format!(
+                "Duty screen indicates elevated wear risk (score {:.0}, PV {:.2}x limit).",
+                duty.risk_score, duty.pv_utilization
+            ),
+        );
     }
 
-    (codes, msgs)
+    w
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    fn base() -> BushingInput {
-        BushingInput {
-            units: "imperial".into(),
-            bore_dia: 0.5005,
-            id_bushing: 0.25,
-            interference: 0.0015,
-            bore_tol_mode: ToleranceMode::NominalTol,
-            bore_nominal: None,
-            bore_tol_plus: None,
-            bore_tol_minus: None,
-            bore_lower: None,
-            bore_upper: None,
-            interference_tol_mode: ToleranceMode::NominalTol,
-            interference_nominal: None,
-            interference_tol_plus: None,
-            interference_tol_minus: None,
-            interference_lower: None,
-            interference_upper: None,
-            interference_policy: InterferenceEnforcementPolicy::default(),
-            bore_capability: BoreProcessCapability::default(),
-            enforce_interference_tolerance: false,
-            lock_bore_for_interference: false,
-            housing_len: 0.75,
-            housing_width: 1.5,
-            edge_dist: 0.75,
-            bushing_type: BushingType::Straight,
-            id_type: IdType::Straight,
-            cs_mode: CsMode::DepthAngle,
-            cs_dia: 0.0,
-            cs_depth: 0.0,
-            cs_depth_tol_plus: None,
-            cs_depth_tol_minus: None,
-            cs_angle: 100.0,
-            ext_cs_mode: CsMode::DepthAngle,
-            ext_cs_dia: 0.0,
-            ext_cs_depth: 0.0,
-            ext_cs_depth_tol_plus: None,
-            ext_cs_depth_tol_minus: None,
-            ext_cs_angle: 100.0,
-            flange_dia: None,
-            flange_od: None,
-            flange_thk: None,
-            mat_housing: "Al_7075_T6".into(),
-            mat_bushing: "SS_17_4_PH".into(),
-            friction: 0.15,
-            d_t: 0.0,
-            assembly_housing_temperature: None,
-            assembly_bushing_temperature: None,
-            process_route_id: BushingProcessRouteId::PressFitOnly,
-            standards_basis: BushingStandardsBasis::ShopDefault,
-            standards_revision: None,
-            process_spec: None,
-            approval_notes: None,
-            criticality: BushingCriticality::General,
-            min_wall_straight: 0.010,
-            min_wall_neck: 0.005,
-            end_constraint: EndConstraint::Free,
-            load: None,
-            edge_load_angle_deg: None,
-            service_temperature_hot: None,
-            service_temperature_cold: None,
-            finish_ream_allowance: None,
-            wear_allowance: None,
-            load_spectrum: BushingLoadSpectrum::Static,
-            oscillation_angle_deg: None,
-            oscillation_freq_hz: None,
-            duty_cycle_pct: None,
-            lubrication_mode: BushingLubricationMode::Dry,
-            contamination_level: BushingContaminationLevel::Clean,
-            surface_roughness_ra_um: None,
-            shaft_hardness_hrc: None,
-            misalignment_deg: None,
-            id_cs: None,
-            od_cs: None,
-        }
-    }
+    use crate::test_support::input_with;
+    use serde_json::json;
 
     #[test]
     fn compute_positive_pressure() {
-        let out = compute(&base()).expect("should compute");
+        let out = compute(&input_with(json!({}))).expect("should compute");
         assert!(out.pressure > 0.0, "pressure should be > 0, got {}", out.pressure);
     }
 
     #[test]
     fn compute_no_interference_zero_pressure() {
-        let mut inp = base();
+        let mut inp = input_with(json!({}));
         inp.interference = 0.0;
         let out = compute(&inp).expect("should compute");
         assert_eq!(out.pressure, 0.0);
     }
+
+    #[test]
+    fn metric_warnings_quote_the_reported_units() {
+        let mut inp = input_with(json!({}));
+        inp.units     = UnitSystem::Metric;
+        inp.edge_dist = 15.0 / units::MM_PER_IN;
+        let out = compute(&inp).expect("should compute");
+        let msg = out.warnings.iter().find(|w| w.starts_with("Edge distance")).expect("edge distance warning");
+        assert!(msg.starts_with("Edge distance 15.0000 mm < "), "{msg}");
+        assert!((out.edge_distance.ed_actual - 15.0).abs() < 1e-9);
+    }
 }
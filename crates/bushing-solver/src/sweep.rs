@@ -0,0 +1,261 @@
+/// Parametric sweep: one or two input fields stepped over a grid, each point
+/// solved in parallel, reported as a table of selected output quantities.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    approval::RuleBook,
+    error::BushingError,
+    inputs::snake_case,
+    materials::MaterialLibrary,
+    schema,
+    sizing::{self, SearchRange},
+    solver,
+    types::*,
+    units::{self, Scale},
+};
+
+/// Reported when a request names no quantities.
+pub const DEFAULT_QUANTITIES: [&str; 5] = [
+    "pressure",
+    "hoop.housing_sigma",
+    "hoop.housing_ms",
+    "governing.margin",
+    "physics.install_force",
+];
+
+/// A swept `BushingInput` field (snake_case or camelCase, e.g. `d_t` / `dT`).
+/// Values are in the base input's unit system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepAxis {
+    pub field: String,
+    #[serde(flatten)]
+    pub range: SearchRange,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepRequest {
+    pub x: SweepAxis,
+    #[serde(default)]
+    pub y: Option<SweepAxis>,
+    /// Dotted paths into `BushingOutput`, e.g. `hoop.housing_ms`.
+    #[serde(default)]
+    pub quantities: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepRow {
+    /// Swept values followed by the quantities, all in the base input's unit
+    /// system; `None` where a quantity is absent.
+    pub values: Vec<Option<f64>>,
+    /// Validation or solve failure at this point.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepTable {
+    /// Swept fields with their unit, e.g. `interference [mm]`, then the quantities.
+    pub columns: Vec<String>,
+    pub rows: Vec<SweepRow>,
+}
+
+impl SweepTable {
+    pub fn to_csv(&self) -> String {
+        let mut csv = self.columns.join(",") + ",error\n";
+        for row in &self.rows {
+            let cells: Vec<String> = row.values.iter().map(|v| v.map(|x| x.to_string()).unwrap_or_default()).collect();
+            let error = row.error.as_ref().map(|e| format!("\"{}\"", e.replace('"', "\"\""))).unwrap_or_default();
+            csv.push_str(&format!("{},{error}\n", cells.join(",")));
+        }
+        csv
+    }
+}
+
+/// Set one numeric input field. Bore and interference carry their nominal
+/// and limit band along; other fields are set through the serde layout.
+pub(crate) fn set_field(input: &mut BushingInput, field: &str, value: f64) -> Result<(), BushingError> {
+    match field {
+        "bore_dia"     => sizing::set_bore(input, value),
+        "interference" => sizing::set_interference(input, value),
+        _ => {
+            let mut v = serde_json::to_value(&*input)?;
+            let slot = v
+                .get_mut(field)
+                .ok_or_else(|| BushingError::InvalidInput(format!("unknown sweep field '{field}'")))?;
+            *slot = Value::from(value);
+            *input = serde_json::from_value(v)
+                .map_err(|_| BushingError::InvalidInput(format!("sweep field '{field}' is not numeric")))?;
+        }
+    }
+    Ok(())
+}
+
+fn quantity(out: &Value, path: &str) -> Option<f64> {
+    out.pointer(&format!("/{}", path.replace('.', "/")))?.as_f64()
+}
+
+fn evaluate(base: &BushingInput, axes: &[(String, f64)], quantities: &[String], rules: &RuleBook, library: &MaterialLibrary) -> SweepRow {
+    let mut values: Vec<Option<f64>> = axes.iter().map(|(_, x)| Some(*x)).collect();
+    let k = Scale::to_internal(base.units);
+    let solved = (|| {
+        let mut input = base.clone();
+        for (field, x) in axes {
+            set_field(&mut input, field, k.value(units::dimension(field), *x))?;
+        }
+        schema::validate(&input)?;
+        Ok::<_, BushingError>(serde_json::to_value(solver::compute_with(&input, rules, library)?)?)
+    })();
+    match solved {
+        Ok(out) => {
+            values.extend(quantities.iter().map(|q| quantity(&out, q)));
+            SweepRow { values, error: None }
+        }
+        Err(e) => {
+            values.extend(quantities.iter().map(|_| None));
+            SweepRow { values, error: Some(e.to_string()) }
+        }
+    }
+}
+
+/// Solve `base` at every grid point of the request, x outer and y inner,
+/// with the approval review against `rules` and materials from `library`.
+pub fn sweep(base: &BushingInput, req: &SweepRequest, rules: &RuleBook, library: &MaterialLibrary) -> Result<SweepTable, BushingError> {
+    let mut axes = vec![(snake_case(&req.x.field), req.x.range.values(&req.x.field)?)];
+    if let Some(y) = &req.y {
+        axes.push((snake_case(&y.field), y.range.values(&y.field)?));
+    }
+    sizing::check_grid(&axes.iter().map(|(_, values)| values.len()).collect::<Vec<_>>())?;
+    // Reject unknown or non-numeric fields once, before spawning the grid
+    for (field, values) in &axes {
+        set_field(&mut base.clone(), field, values[0])?;
+    }
+    let quantities: Vec<String> = if req.quantities.is_empty() {
+        DEFAULT_QUANTITIES.iter().map(|q| q.to_string()).collect()
+    } else {
+        req.quantities.clone()
+    };
+
+    let mut points: Vec<Vec<(String, f64)>> = vec![];
+    for &x in &axes[0].1 {
+        let at_x = vec![(axes[0].0.clone(), x)];
+        match axes.get(1) {
+            Some((y_field, ys)) => points.extend(ys.iter().map(|&y| [at_x.clone(), vec![(y_field.clone(), y)]].concat())),
+            None                => points.push(at_x),
+        }
+    }
+
+    let workers = std::thread::available_parallelism().map_or(1, |n| n.get()).clamp(1, points.len());
+    let chunk   = points.len().div_ceil(workers);
+    let rows    = std::thread::scope(|s| {
+        let handles: Vec<_> = points
+            .chunks(chunk)
+            .map(|c| s.spawn(|| c.iter().map(|p| evaluate(base, p, &quantities, rules, library)).collect::<Vec<_>>()))
+            .collect();
+        handles.into_iter().flat_map(|h| h.join().expect("sweep worker panicked")).collect()
+    });
+
+    let labels = units::labels(base.units);
+    let mut columns: Vec<String> = axes
+        .iter()
+        .map(|(f, _)| match units::label(&labels, units::dimension(f)) {
+            ""    => f.clone(),
+            label => format!("{f} [{label}]"),
+        })
+        .collect();
+    columns.extend(quantities);
+    Ok(SweepTable { columns, rows })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{input_with, metric, overlay, raw_with};
+    use serde_json::json;
+
+    fn run(req: &SweepRequest) -> Result<SweepTable, BushingError> {
+        sweep(&input_with(json!({})), req, RuleBook::builtin(), MaterialLibrary::builtin())
+    }
+
+    fn axis(field: &str, min: f64, max: f64, steps: usize) -> SweepAxis {
+        SweepAxis { field: field.into(), range: SearchRange { min, max, steps } }
+    }
+
+    #[test]
+    fn two_axis_grid_matches_direct_solves() {
+        let req = SweepRequest {
+            x: axis("interference", 0.001, 0.002, 3),
+            y: Some(axis("dT", -50.0, 50.0, 2)),
+            quantities: vec!["pressure".into(), "governing.margin".into()],
+        };
+        let table = run(&req).unwrap();
+        assert_eq!(table.columns, ["interference [in]", "d_t [°F]", "pressure", "governing.margin"]);
+        assert_eq!(table.rows.len(), 6);
+        let row = &table.rows[3];
+        assert!((row.values[0].unwrap() - 0.0015).abs() < 1e-15 && row.values[1] == Some(50.0));
+        let mut input = input_with(json!({}));
+        sizing::set_interference(&mut input, row.values[0].unwrap());
+        input.d_t = 50.0;
+        let direct = solver::compute(&input).unwrap();
+        assert!((row.values[2].unwrap() - direct.pressure).abs() < 1e-12);
+        assert_eq!(row.values[3], Some(direct.governing.margin));
+    }
+
+    #[test]
+    fn invalid_points_are_reported_per_row() {
+        let req = SweepRequest { x: axis("edgeDist", 0.2, 1.0, 2), y: None, quantities: vec![] };
+        let table = run(&req).unwrap();
+        assert_eq!(table.columns.len(), 1 + DEFAULT_QUANTITIES.len());
+        assert!(table.rows[0].error.is_some() && table.rows[0].values[1].is_none());
+        assert!(table.rows[1].error.is_none());
+        let csv = table.to_csv();
+        assert!(csv.starts_with("edge_dist [in],pressure,"));
+        assert_eq!(csv.lines().count(), 3);
+    }
+
+    #[test]
+    fn entry_point_honours_mode_library_and_base_validation() {
+        let raw = |extra: Value| raw_with(overlay(json!({ "matHousing": "Shop_Al" }), extra));
+        let req = SweepRequest { x: axis("interference", 0.001, 0.002, 2), y: None, quantities: vec![] };
+        let mut library = MaterialLibrary::builtin().clone();
+        let mut shop    = library.get("Al_7075_T6").unwrap().clone();
+        shop.id = "Shop_Al".into();
+        library.materials.push(shop);
+
+        let run = |v: Value, mode: InputMode| crate::sweep_bushing_with(raw(v), &req, mode, RuleBook::builtin(), &library);
+        assert!(run(json!({}), InputMode::Strict).unwrap().rows.iter().all(|r| r.error.is_none()));
+        let builtin = crate::sweep_bushing(raw(json!({})), &req).unwrap();
+        assert!(builtin.rows.iter().all(|r| r.error.as_deref().is_some_and(|e| e.contains("Shop_Al"))));
+        // Strict findings and an invalid base fail once rather than per row
+        assert!(matches!(run(json!({ "frictionCoef": 0.2 }), InputMode::Strict), Err(BushingError::Schema(_))));
+        assert!(matches!(run(json!({ "edgeDist": 0.2 }), InputMode::Lenient), Err(BushingError::Schema(_))));
+    }
+
+    #[test]
+    fn metric_axis_values_are_taken_in_the_input_units() {
+        let metric = input_with(metric(json!({})));
+        let req = SweepRequest { x: axis("boreDia", 12.0, 13.0, 2), y: None, quantities: vec!["pressure".into()] };
+        let table = sweep(&metric, &req, RuleBook::builtin(), MaterialLibrary::builtin()).unwrap();
+        assert_eq!(table.columns, ["bore_dia [mm]", "pressure"]);
+        assert!(table.rows.iter().all(|r| r.error.is_none()), "{:?}", table.rows);
+        assert_eq!(table.rows[1].values[0], Some(13.0));
+        let mut input = metric.clone();
+        sizing::set_bore(&mut input, 13.0 / units::MM_PER_IN);
+        let direct = solver::compute(&input).unwrap();
+        assert!((table.rows[1].values[1].unwrap() - direct.pressure).abs() < 1e-9 * direct.pressure);
+    }
+
+    #[test]
+    fn oversized_grid_is_rejected_before_solving() {
+        let steps = sizing::MAX_STEPS;
+        let req   = SweepRequest { x: axis("interference", 0.001, 0.002, steps), y: Some(axis("dT", -50.0, 50.0, steps)), quantities: vec![] };
+        assert!(matches!(run(&req), Err(BushingError::InvalidInput(m)) if m.contains("grid has")));
+    }
+
+    #[test]
+    fn unknown_or_non_numeric_field_is_rejected() {
+        let bad = SweepRequest { x: axis("boreDiameter", 0.4, 0.5, 2), y: None, quantities: vec![] };
+        assert!(matches!(run(&bad), Err(BushingError::InvalidInput(_))));
+        let text = SweepRequest { x: axis("matHousing", 0.4, 0.5, 2), y: None, quantities: vec![] };
+        assert!(matches!(run(&text), Err(BushingError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,42 @@
+/// Shared test fixtures: a half-inch straight bushing in an aluminium lug,
+/// with any keys of `extra` set over it.
+use serde_json::{json, Value};
+
+use crate::{inputs::BushingInputRaw, solver, types::*};
+
+/// `base` with every key of `extra` set over it.
+pub(crate) fn overlay(mut base: Value, extra: Value) -> Value {
+    for (k, x) in extra.as_object().expect("extra keys must be an object") {
+        base[k] = x.clone();
+    }
+    base
+}
+
+/// The base document in millimetres, with `extra` set over it.
+pub(crate) fn metric(extra: Value) -> Value {
+    overlay(
+        json!({
+            "units": "metric", "boreDia": 12.7, "idBushing": 6.35, "interference": 0.0381,
+            "housingLen": 19.05, "housingWidth": 38.1, "edgeDist": 25.4,
+        }),
+        extra,
+    )
+}
+
+pub(crate) fn raw_with(extra: Value) -> BushingInputRaw {
+    BushingInputRaw(overlay(
+        json!({
+            "boreDia": 0.5, "idBushing": 0.25, "interference": 0.0015,
+            "housingLen": 0.75, "housingWidth": 1.5, "edgeDist": 1.0,
+        }),
+        extra,
+    ))
+}
+
+pub(crate) fn input_with(extra: Value) -> BushingInput {
+    raw_with(extra).normalize().unwrap()
+}
+
+pub(crate) fn solve_with(extra: Value) -> BushingOutput {
+    solver::compute(&input_with(extra)).unwrap()
+}
@@ -0,0 +1,168 @@
+/// Thermal-assist (shrink fit) installation planner.
+/// Sizes the heat / chill needed for an assembly clearance, applies the entered
+/// assembly temperatures to the install state and estimates how long the
+/// clearance lasts before the parts equalise.
+use crate::{
+    math::EPS,
+    process,
+    service::{self, ServiceInputs},
+    solver,
+    types::*,
+    units::Show,
+};
+
+/// Default diametral assembly clearance per inch of bore when no target is entered.
+const DEFAULT_CLEARANCE_PER_IN: f64 = 0.001;
+/// Natural-convection film coefficient to still air, BTU/hr/in²/°F (≈ 10 W/m²K).
+const FILM_COEFF: f64 = 0.0122;
+/// Bisection iterations for the time-window root.
+const WINDOW_ITERATIONS: usize = 60;
+
+pub(crate) fn has_assembly_temperatures(input: &BushingInput) -> bool {
+    input.assembly_housing_temperature.is_some() || input.assembly_bushing_temperature.is_some()
+}
+
+/// Diametral growth per °F of a part with expansion coefficient `alpha_u_f`.
+fn growth_per_degree(alpha_u_f: f64, dia: f64) -> Option<f64> {
+    let k = alpha_u_f * dia * 1e-6;
+    (k > 0.0).then_some(k)
+}
+
+pub fn build_thermal_plan(si: &ServiceInputs<'_>) -> ThermalAssistPlan {
+    let input  = si.input;
+    let dia    = si.bore_dia;
+    let target = input.assembly_clearance_target.unwrap_or(DEFAULT_CLEARANCE_PER_IN * dia);
+    let active = has_assembly_temperatures(input);
+    let dt_h   = input.assembly_housing_temperature.map_or(0.0, service::temperature_delta_f);
+    let dt_b   = input.assembly_bushing_temperature.map_or(0.0, service::temperature_delta_f);
+
+    let relief        = (si.mat_h.alpha_u_f * dt_h - si.mat_b.alpha_u_f * dt_b) * dia * 1e-6;
+    let install_delta = si.delta_user - relief;
+    let needed        = (si.delta_user + target).max(0.0);
+    let clearance     = (-install_delta).max(0.0);
+    let target_met    = active && clearance + EPS >= target;
+    let time_window_min = if active { time_window_min(si, dt_h, dt_b) } else { None };
+
+    let show      = Show::new(si.input.units);
+    let mut notes = vec![];
+    if !active {
+        notes.push("No assembly temperatures entered; install state equals the service fit.".into());
+    } else if install_delta > 0.0 {
+        notes.push(format!(
+            "Assembly temperatures leave {} of interference; parts will still be pressed.",
+            show.length(install_delta, 5)
+        ));
+    } else if !target_met {
+        notes.push(format!(
+            "Assembly clearance {} is below the {} target.",
+            show.length(clearance, 5),
+            show.length(target, 5)
+        ));
+    }
+    if si.delta_user <= 0.0 {
+        notes.push("Fit has no ambient interference; thermal assist is not needed.".into());
+    }
+
+    ThermalAssistPlan {
+        active,
+        target_clearance: target,
+        required_housing_delta_t_f: growth_per_degree(si.mat_h.alpha_u_f, dia).map(|k| needed / k),
+        required_bushing_delta_t_f: growth_per_degree(si.mat_b.alpha_u_f, dia).map(|k| -needed / k),
+        housing_delta_t_f: dt_h,
+        bushing_delta_t_f: dt_b,
+        assembly_thermal_delta: if active { relief } else { 0.0 },
+        install_delta_effective: if active { install_delta } else { si.delta_user },
+        assembly_clearance: if active { clearance } else { 0.0 },
+        target_met,
+        time_window_min,
+        notes,
+    }
+}
+
+/// Lumped-capacitance time (minutes) for the conditioned parts to relax in still
+/// air until the relieved interference returns to zero clearance. The housing is
+/// treated as a plate of thickness `housing_len`, the bushing as its straight wall.
+/// Returns `None` when the fit never re-establishes interference.
+fn time_window_min(si: &ServiceInputs<'_>, dt_h: f64, dt_b: f64) -> Option<f64> {
+    let input = si.input;
+    if si.delta_user <= 0.0 {
+        return None;
+    }
+    let wall_b = ((si.bore_dia - input.id_bushing) / 2.0).max(EPS);
+    let tau_h  = si.mat_h.density_lb_in3 * si.mat_h.cp_btu_lb_f * (input.housing_len / 2.0) / FILM_COEFF;
+    let tau_b  = si.mat_b.density_lb_in3 * si.mat_b.cp_btu_lb_f * (wall_b / 2.0) / FILM_COEFF;
+    let relief = |t: f64| {
+        (si.mat_h.alpha_u_f * dt_h * (-t / tau_h.max(EPS)).exp()
+            - si.mat_b.alpha_u_f * dt_b * (-t / tau_b.max(EPS)).exp())
+            * si.bore_dia
+            * 1e-6
+    };
+    if relief(0.0) <= si.delta_user {
+        return Some(0.0);
+    }
+    let (mut lo, mut hi) = (0.0, 20.0 * tau_h.max(tau_b));
+    for _ in 0..WINDOW_ITERATIONS {
+        let mid = 0.5 * (lo + hi);
+        if relief(mid) > si.delta_user {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(0.5 * (lo + hi) * 60.0)
+}
+
+/// Replace the install-state physics with the thermally assisted values.
+pub fn apply_install_state(si: &ServiceInputs<'_>, plan: &ThermalAssistPlan, physics: &mut PhysicsResult) {
+    if !plan.active {
+        return;
+    }
+    let input    = si.input;
+    let pressure = solver::solve_point(input, si.mat_h, si.mat_b, si.bore_dia, plan.install_delta_effective, 0.0).pressure;
+    physics.install_delta_effective  = plan.install_delta_effective;
+    physics.install_contact_pressure = pressure;
+    physics.install_force            = process::press_force(input.friction, pressure, si.bore_dia, input.housing_len);
+    physics.assembly_thermal_delta   = plan.assembly_thermal_delta;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::solve_with;
+    use serde_json::json;
+
+    #[test]
+    fn required_delta_t_matches_pair_expansion() {
+        let plan = solve_with(json!({})).process.thermal_plan;
+        assert!(!plan.active);
+        // Al 7075 housing, 0.0015 interference + 0.0005 default clearance on a 0.5 in bore
+        let expected = 0.0020 / (12.9 * 0.5 * 1e-6);
+        assert!((plan.required_housing_delta_t_f.unwrap() - expected).abs() < 1e-6);
+        assert!(plan.required_bushing_delta_t_f.unwrap() < 0.0);
+        assert!(plan.time_window_min.is_none());
+    }
+
+    #[test]
+    fn heated_housing_opens_clearance_and_drops_install_force() {
+        let base = solve_with(json!({}));
+        let out  = solve_with(json!({ "assemblyHousingTemperature": 450.0, "assemblyBushingTemperature": -100.0 }));
+        let plan = &out.process.thermal_plan;
+        assert!(out.process.assembly_thermal_assist_active);
+        assert!(plan.target_met, "clearance {}", plan.assembly_clearance);
+        assert!(out.physics.install_delta_effective < 0.0);
+        assert_eq!(out.physics.install_force, 0.0);
+        assert!(base.physics.install_force > 0.0);
+        assert!((out.physics.assembly_thermal_delta - plan.assembly_thermal_delta).abs() < 1e-15);
+        let window = plan.time_window_min.unwrap();
+        assert!(window > 0.0 && window < 60.0, "window {window}");
+    }
+
+    #[test]
+    fn partial_assist_still_presses() {
+        let out  = solve_with(json!({ "assemblyHousingTemperature": 150.0 }));
+        let plan = &out.process.thermal_plan;
+        assert!(plan.active && !plan.target_met);
+        assert!(out.physics.install_delta_effective > 0.0);
+        assert!(out.physics.install_force < out.physics.retained_install_force);
+        assert_eq!(plan.time_window_min, Some(0.0));
+    }
+}
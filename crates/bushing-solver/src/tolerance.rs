@@ -0,0 +1,212 @@
+/// Bore / OD / interference tolerance stack and interference enforcement policy.
+/// Ported from solveEngine.ts::computeState() (tolerance section).
+use crate::{
+    math::{self, OdFitStatus, ToleranceSpec, BORE_FLOOR, EPS},
+    types::*,
+    units::Show,
+};
+
+/// Resolve the bore and interference bands, apply the [`InterferenceEnforcementPolicy`]
+/// and derive the bushing OD and achieved-interference bands.
+pub fn build_tolerance(input: &BushingInput) -> ToleranceResult {
+    let mut bore = math::resolve_tolerance(
+        &ToleranceSpec {
+            mode: input.bore_tol_mode,
+            nominal: input.bore_nominal.unwrap_or(input.bore_dia),
+            tol_plus: input.bore_tol_plus,
+            tol_minus: input.bore_tol_minus,
+            lower: input.bore_lower,
+            upper: input.bore_upper,
+        },
+        BORE_FLOOR,
+    );
+    let target = math::resolve_tolerance(
+        &ToleranceSpec {
+            mode: input.interference_tol_mode,
+            nominal: input.interference_nominal.unwrap_or(input.interference),
+            tol_plus: input.interference_tol_plus,
+            tol_minus: input.interference_tol_minus,
+            lower: input.interference_lower,
+            upper: input.interference_upper,
+        },
+        f64::NEG_INFINITY,
+    );
+
+    let policy       = &input.interference_policy;
+    let capability   = &input.bore_capability;
+    let enabled      = policy.enabled.unwrap_or(input.enforce_interference_tolerance);
+    let reamer_fixed = capability.mode.as_deref() == Some("reamer_fixed");
+    let lock_bore    = policy.lock_bore.unwrap_or(input.lock_bore_for_interference || reamer_fixed);
+    let preserve_nominal = policy.preserve_bore_nominal.unwrap_or(true);
+    let allow_shift  = policy.allow_bore_nominal_shift.unwrap_or(false);
+
+    let available_width = (bore.upper - bore.lower).max(0.0);
+    let target_width    = (target.upper - target.lower).max(0.0);
+
+    let mut fit          = math::build_od_tolerance(&bore, &target);
+    let mut notes        = vec![];
+    let mut reason_codes = vec![];
+    let mut shift_applied = 0.0;
+    let show = Show::new(input.units);
+
+    if let Some(max_w) = capability.max_recommended_tol_width.filter(|w| available_width > w + EPS) {
+        notes.push(format!(
+            "Entered bore tolerance width {} exceeds the recommended process width {}.",
+            show.length(available_width, 4),
+            show.length(max_w, 4),
+        ));
+    }
+
+    if !enabled {
+        reason_codes.push(InterferenceEnforcementReasonCode::EnforcementDisabled);
+    } else if fit.status == OdFitStatus::Infeasible {
+        if lock_bore {
+            notes.push("Strict interference enforcement is blocked because bore is locked (reamer-fixed).".into());
+            reason_codes.push(InterferenceEnforcementReasonCode::BlockedBoreLocked);
+        } else {
+            let enforced = math::enforce_bore_band(
+                &bore,
+                &target,
+                capability,
+                allow_shift && !preserve_nominal,
+                policy.max_bore_nominal_shift,
+                &show,
+            );
+            notes.extend(enforced.note);
+            if enforced.changed {
+                bore          = enforced.adjusted;
+                shift_applied = enforced.nominal_shift;
+                fit           = math::build_od_tolerance(&bore, &target);
+                reason_codes.push(InterferenceEnforcementReasonCode::AutoAdjustBoreWidth);
+            } else {
+                if enforced.capability_blocked {
+                    reason_codes.push(InterferenceEnforcementReasonCode::BlockedCapabilityFloor);
+                } else {
+                    reason_codes.push(InterferenceEnforcementReasonCode::BlockedInfeasibleWidth);
+                }
+                if allow_shift && !preserve_nominal {
+                    notes.push("Bore nominal shift cannot resolve width-driven interference infeasibility.".into());
+                    reason_codes.push(InterferenceEnforcementReasonCode::BlockedNominalShiftNoEffect);
+                }
+            }
+        }
+    }
+
+    let (lower_violation, upper_violation) = math::containment_violations(&fit.achieved_interference, &target);
+    let satisfied = !enabled || (lower_violation <= EPS && upper_violation <= EPS);
+    let blocked   = enabled && !satisfied;
+    if enabled && satisfied {
+        reason_codes.push(InterferenceEnforcementReasonCode::ContainmentSatisfied);
+    }
+    notes.extend(fit.notes);
+
+    ToleranceResult {
+        status: fit.status.as_str().into(),
+        notes,
+        enforcement: ToleranceEnforcement {
+            enabled,
+            satisfied,
+            blocked,
+            reason_codes,
+            required_bore_tol_width: target_width,
+            available_bore_tol_width: available_width,
+            target_interference_width: target_width,
+            lower_violation,
+            upper_violation,
+            bore_nominal_shift_applied: shift_applied,
+        },
+        bore,
+        interference_target: target,
+        od_bushing: fit.od,
+        achieved_interference: fit.achieved_interference,
+        cs_internal_dia: None,
+        cs_internal_depth: None,
+        cs_external_dia: None,
+        cs_external_depth: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{input_with, overlay};
+    use serde_json::json;
+
+    fn input(extra: serde_json::Value) -> BushingInput {
+        let tolerances = json!({
+            "edgeDist": 0.75, "boreTolPlus": 0.0010, "boreTolMinus": 0.0,
+            "interferenceTolPlus": 0.0003, "interferenceTolMinus": 0.0003,
+        });
+        input_with(overlay(tolerances, extra))
+    }
+
+    #[test]
+    fn ranges_follow_entered_tolerances() {
+        let t = build_tolerance(&input(json!({})));
+        assert!((t.bore.lower - 0.5000).abs() < 1e-12);
+        assert!((t.bore.upper - 0.5010).abs() < 1e-12);
+        assert!((t.interference_target.lower - 0.0012).abs() < 1e-12);
+        // Bore band (0.0010) wider than interference band (0.0006) → infeasible
+        assert_eq!(t.status, "infeasible");
+        assert_eq!(t.enforcement.reason_codes, vec![InterferenceEnforcementReasonCode::EnforcementDisabled]);
+        assert!(t.enforcement.satisfied);
+    }
+
+    #[test]
+    fn feasible_stack_contains_interference() {
+        let t = build_tolerance(&input(json!({
+            "boreTolPlus": 0.0002, "boreTolMinus": 0.0002, "enforceInterferenceTolerance": true,
+        })));
+        assert_eq!(t.status, "ok");
+        assert!(t.achieved_interference.lower >= t.interference_target.lower - 1e-12);
+        assert!(t.achieved_interference.upper <= t.interference_target.upper + 1e-12);
+        assert!((t.od_bushing.lower - (0.5002 + 0.0012)).abs() < 1e-12);
+        assert!((t.od_bushing.nominal - 0.5015).abs() < 1e-12);
+        assert_eq!(t.enforcement.reason_codes, vec![InterferenceEnforcementReasonCode::ContainmentSatisfied]);
+    }
+
+    #[test]
+    fn locked_bore_blocks_enforcement() {
+        let t = build_tolerance(&input(json!({ "enforceInterferenceTolerance": true, "lockBoreForInterference": true })));
+        assert!(t.enforcement.blocked);
+        assert!(t.enforcement.reason_codes.contains(&InterferenceEnforcementReasonCode::BlockedBoreLocked));
+        assert!(t.enforcement.upper_violation > 0.0 || t.enforcement.lower_violation > 0.0);
+    }
+
+    #[test]
+    fn unlocked_bore_is_tightened() {
+        let t = build_tolerance(&input(json!({ "enforceInterferenceTolerance": true })));
+        assert!(!t.enforcement.blocked);
+        assert!((t.bore.upper - t.bore.lower - 0.0006).abs() < 1e-12);
+        assert!((t.bore.nominal - 0.5000).abs() < 1e-12, "nominal preserved");
+        assert_eq!(
+            t.enforcement.reason_codes,
+            vec![InterferenceEnforcementReasonCode::AutoAdjustBoreWidth, InterferenceEnforcementReasonCode::ContainmentSatisfied]
+        );
+    }
+
+    #[test]
+    fn capability_floor_blocks_tightening() {
+        let t = build_tolerance(&input(json!({
+            "enforceInterferenceTolerance": true,
+            "boreCapability": { "minAchievableTolWidth": 0.0008 },
+        })));
+        assert!(t.enforcement.blocked);
+        assert!(t.enforcement.reason_codes.contains(&InterferenceEnforcementReasonCode::BlockedCapabilityFloor));
+    }
+
+    #[test]
+    fn nominal_shift_is_bounded() {
+        let t = build_tolerance(&input(json!({
+            "interferencePolicy": {
+                "enabled": true,
+                "preserveBoreNominal": false,
+                "allowBoreNominalShift": true,
+                "maxBoreNominalShift": 0.0001,
+            },
+        })));
+        assert!((t.enforcement.bore_nominal_shift_applied - 0.0001).abs() < 1e-12);
+        assert!((t.bore.nominal - 0.5001).abs() < 1e-12);
+        assert!(t.enforcement.satisfied);
+    }
+}
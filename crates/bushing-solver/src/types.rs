@@ -2,6 +2,30 @@ use serde::{Deserialize, Serialize};
 
 // ── Primitives ────────────────────────────────────────────────────────────────
 
+/// Unit system of the entered input and the emitted output. Internally the
+/// solver works in inches, ksi, lbf and °F.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitSystem {
+    /// in, ksi, lbf, °F
+    #[default]
+    Imperial,
+    /// mm, MPa, N, °C
+    Metric,
+}
+
+/// Units of the dimensional fields in a [`BushingOutput`]. Fields whose name
+/// carries a unit suffix (`pressure_psi`, `_um`, `_delta_t_f`, …) keep it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct UnitLabels {
+    pub system: UnitSystem,
+    pub length: String,
+    pub area: String,
+    pub stress: String,
+    pub force: String,
+    pub temperature: String,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum CsMode {
@@ -35,6 +59,7 @@ pub enum BushingProcessRouteId {
 pub enum BushingStandardsBasis {
     #[default]
     ShopDefault,
+    #[serde(rename = "faa_ac_43_13")]
     FaaAc4313,
     NasMs,
     SaeAms,
@@ -105,6 +130,39 @@ pub enum EndConstraint {
     BothEnds,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureCriterion {
+    /// Largest principal stress magnitude.
+    MaxPrincipal,
+    /// Maximum shear (largest principal difference).
+    Tresca,
+    #[default]
+    VonMises,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AllowableBasis {
+    #[default]
+    Yield,
+    /// Ftu divided by the fitting factor.
+    Ultimate,
+    Bearing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BushingAnalysisMode {
+    /// Single solve at the nominal fit.
+    #[default]
+    Nominal,
+    /// Nominal plus the min/max interference corners of the tolerance band.
+    WorstCase,
+    /// Worst-case corners plus RSS limits and a seeded Monte Carlo run.
+    Statistical,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum InterferenceEnforcementReasonCode {
@@ -118,7 +176,17 @@ pub enum InterferenceEnforcementReasonCode {
     BlockedNominalShiftNoEffect,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+/// Bushing material family keying the PV / wear table (against a steel pin).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WearFamily {
+    Bronze,
+    Steel,
+    Titanium,
+    Aluminum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum WearRisk {
     #[default]
@@ -165,6 +233,12 @@ pub enum BushingWarningCode {
     ApprovalReviewRequired,
     EdgeDistanceSequenceFail,
     EdgeDistanceStrengthFail,
+    MaterialBasisUnavailable,
+    InputUnknownKey,
+    InputEnumInvalid,
+    InputAliasConflict,
+    InputMigrated,
+    WearFamilyUnknown,
 }
 
 // ── Shared sub-types ──────────────────────────────────────────────────────────
@@ -196,6 +270,41 @@ pub struct BoreProcessCapability {
     pub preferred_it_class: Option<String>,
 }
 
+/// One diameter reading. `station` is the axial position from the entry face
+/// (inches); `angle_deg` the gauge orientation.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MeasuredReading {
+    pub dia: f64,
+    pub station: Option<f64>,
+    pub angle_deg: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MeasuredDimension {
+    /// Reported size; defaults to the mean of `readings`.
+    pub actual: Option<f64>,
+    pub readings: Vec<MeasuredReading>,
+    pub tol_plus: Option<f64>,
+    pub tol_minus: Option<f64>,
+    /// Entered roundness (µm); derived from the readings when absent.
+    pub roundness: Option<f64>,
+    pub ra: Option<f64>,
+}
+
+/// As-built measurements of a specific part, applied in place of nominal
+/// values when `enabled` with the `measured` basis.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MeasuredPart {
+    pub enabled: bool,
+    pub basis: String,
+    pub bore: Option<MeasuredDimension>,
+    pub od: Option<MeasuredDimension>,
+    pub id: Option<MeasuredDimension>,
+    pub edge_dist: Option<f64>,
+    pub housing_width: Option<f64>,
+    pub notes: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CountersinkInput {
     pub enabled: Option<bool>,
@@ -205,10 +314,41 @@ pub struct CountersinkInput {
     pub angle_deg: Option<f64>,
 }
 
+/// Statistical basis of a material's strength allowables: MMPDS A- or
+/// B-basis, or the S-basis (specification minimum) base values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MaterialBasis {
+    A,
+    B,
+    #[default]
+    S,
+}
+
+/// Strength allowables published on one basis (ksi). Absent values fall
+/// back to the material's base (S-basis) values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct BasisAllowables {
+    #[serde(default)]
+    pub sy_ksi: Option<f64>,
+    #[serde(default)]
+    pub ftu_ksi: Option<f64>,
+    #[serde(default)]
+    pub fbru_ksi: Option<f64>,
+    #[serde(default)]
+    pub fsu_ksi: Option<f64>,
+}
+
+/// `[temperature °F, multiplier on the room-temperature value]` points in
+/// ascending temperature, interpolated linearly and held flat past the ends.
+pub type TemperatureCurve = Vec<[f64; 2]>;
+
+/// Material data is always in library units (ksi, µin/in/°F, lb/in³, °F),
+/// whatever the input unit system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MaterialProps {
-    pub id: &'static str,
-    pub name: &'static str,
+    pub id: String,
+    pub name: String,
     /// Young's modulus in ksi
     pub e_ksi: f64,
     /// Yield strength in ksi
@@ -218,11 +358,29 @@ pub struct MaterialProps {
     /// Ultimate shear strength in ksi
     pub fsu_ksi: f64,
     /// Ultimate tensile strength in ksi (optional)
+    #[serde(default)]
     pub ftu_ksi: Option<f64>,
     /// Poisson's ratio
     pub nu: f64,
     /// Thermal expansion coefficient in µin/in/°F
     pub alpha_u_f: f64,
+    /// Density in lb/in³
+    pub density_lb_in3: f64,
+    /// Specific heat in BTU/lb/°F
+    pub cp_btu_lb_f: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub a_basis: Option<BasisAllowables>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub b_basis: Option<BasisAllowables>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub e_curve: TemperatureCurve,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sy_curve: TemperatureCurve,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alpha_curve: TemperatureCurve,
+    /// PV / wear family when the material runs as the bushing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wear_family: Option<WearFamily>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -232,10 +390,49 @@ pub struct BushingWarning {
     pub severity: WarningSeverity,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// How input normalization treats unknown keys, invalid enum strings and
+/// conflicting aliases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum InputMode {
+    /// Fail with every finding as a schema error.
+    Strict,
+    /// Fall back on the defaults and report the findings as warnings.
+    #[default]
+    Lenient,
+}
+
+/// One input-validation finding. `pointer` is a JSON pointer into the
+/// camelCase input (e.g. `/interferencePolicy/lockBore`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub pointer: String,
+    pub code: BushingWarningCode,
+    pub severity: WarningSeverity,
+    pub message: String,
+    pub suggestion: String,
+}
+
+/// Whether a check is a stress margin or a geometric / sequencing ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CandidateKind {
+    /// Margin of safety against an allowable; eligible to govern.
+    #[default]
+    Strength,
+    /// Dimension over its recommended minimum, less one; reported through
+    /// warnings and never governing.
+    Geometry,
+}
+
+/// One computed check. `name` is the failure-mode id.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BushingCandidate {
     pub name: String,
     pub margin: f64,
+    pub location: String,
+    pub load_case: String,
+    pub kind: CandidateKind,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -272,7 +469,9 @@ pub struct StressRegion {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BushingInput {
-    pub units: String,
+    /// System the input was entered in and the results are reported in;
+    /// values here are always internal.
+    pub units: UnitSystem,
     pub bore_dia: f64,
     pub id_bushing: f64,
     pub interference: f64,
@@ -295,6 +494,8 @@ pub struct BushingInput {
     pub housing_len: f64,
     pub housing_width: f64,
     pub edge_dist: f64,
+    /// Lateral offset of the hole centre from the lug centreline (inches).
+    pub hole_offset: Option<f64>,
     pub bushing_type: BushingType,
     pub id_type: IdType,
     pub cs_mode: CsMode,
@@ -314,10 +515,16 @@ pub struct BushingInput {
     pub flange_thk: Option<f64>,
     pub mat_housing: String,
     pub mat_bushing: String,
+    /// Allowable basis applied to both materials.
+    pub material_basis: MaterialBasis,
+    /// Materials defined inline; they take precedence over the library.
+    pub custom_materials: Vec<MaterialProps>,
     pub friction: f64,
     pub d_t: f64,
     pub assembly_housing_temperature: Option<f64>,
     pub assembly_bushing_temperature: Option<f64>,
+    /// Diametral clearance targeted during thermal-assist assembly (inches).
+    pub assembly_clearance_target: Option<f64>,
     pub process_route_id: BushingProcessRouteId,
     pub standards_basis: BushingStandardsBasis,
     pub standards_revision: Option<String>,
@@ -327,7 +534,13 @@ pub struct BushingInput {
     pub min_wall_straight: f64,
     pub min_wall_neck: f64,
     pub end_constraint: EndConstraint,
+    pub failure_criterion: FailureCriterion,
+    pub allowable_basis: AllowableBasis,
+    /// Fitting factor on the ultimate allowable basis; defaults to 1.15.
+    pub fitting_factor: Option<f64>,
     pub load: Option<f64>,
+    /// Axial load on a flanged bushing (lbf); defaults to the install force.
+    pub axial_load: Option<f64>,
     pub edge_load_angle_deg: Option<f64>,
     pub service_temperature_hot: Option<f64>,
     pub service_temperature_cold: Option<f64>,
@@ -344,6 +557,12 @@ pub struct BushingInput {
     pub misalignment_deg: Option<f64>,
     pub id_cs: Option<CountersinkInput>,
     pub od_cs: Option<CountersinkInput>,
+    pub measured_part: Option<MeasuredPart>,
+    pub analysis_mode: BushingAnalysisMode,
+    pub monte_carlo_samples: Option<u32>,
+    pub monte_carlo_seed: Option<u64>,
+    /// One-sigma spread of `d_t` used by the statistical analysis (°F).
+    pub d_t_std_dev: Option<f64>,
 }
 
 // ── BushingOutput ─────────────────────────────────────────────────────────────
@@ -396,15 +615,44 @@ pub struct HoopResult {
     pub housing_ms: f64,
     pub bushing_sigma: f64,
     pub bushing_ms: f64,
+    /// Equivalent stress under the selected criterion at the housing bore /
+    /// bushing ID, including axial stress.
+    pub housing_sigma_eq: f64,
+    pub bushing_sigma_eq: f64,
     pub ligament_sigma: f64,
     pub ligament_ms: f64,
     pub ed_required_ligament: Option<f64>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriterionMargin {
+    pub criterion: FailureCriterion,
+    pub max_stress: f64,
+    /// Radius of the governing field sample.
+    pub at_radius: f64,
+    pub margin: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegionCriteria {
+    pub allowable: f64,
+    pub margins: Vec<CriterionMargin>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriteriaResult {
+    pub selected: FailureCriterion,
+    pub allowable_basis: AllowableBasis,
+    pub fitting_factor: f64,
+    pub housing: RegionCriteria,
+    pub bushing: RegionCriteria,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EdgeDistanceResult {
     pub ed_min_sequence: f64,
-    pub ed_min_strength: f64,
+    /// `None` when no edge distance keeps the ligament at positive margin.
+    pub ed_min_strength: Option<f64>,
     pub ed_actual: f64,
     pub governing: String,
 }
@@ -426,7 +674,8 @@ pub struct PhysicsResult {
     pub stress_axial_bushing: f64,
     pub axial_constraint_factor: f64,
     pub axial_length_factor: f64,
-    pub ed_min_coupled: f64,
+    /// Ligament edge-distance requirement; `None` when it cannot be met.
+    pub ed_min_coupled: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -439,6 +688,46 @@ pub struct GeometryResult {
     pub is_saturation_active: bool,
 }
 
+/// Flanged bushing checks. Stresses in ksi, areas in in², load in lbf.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlangeResult {
+    pub flange_od: f64,
+    pub flange_thk: f64,
+    pub neck_wall: f64,
+    pub axial_load: f64,
+    /// `entered` or `install_force`.
+    pub axial_load_source: String,
+    pub bearing_area: f64,
+    pub bearing_stress: f64,
+    pub bearing_allowable: f64,
+    pub bearing_ms: f64,
+    pub shear_area: f64,
+    pub shear_stress: f64,
+    pub shear_allowable: f64,
+    pub shear_ms: f64,
+}
+
+/// One bearing-path check. Stresses in ksi, area in in².
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BearingCheck {
+    pub area: f64,
+    pub stress: f64,
+    pub allowable: f64,
+    pub margin: f64,
+}
+
+/// Pin load carried through the bushing into the housing lug. Loads in lbf.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BearingResult {
+    pub load: f64,
+    /// Component of `load` directed at the edge (lug axis).
+    pub edge_load: f64,
+    pub pin_bearing: BearingCheck,
+    pub bushing_housing_bearing: BearingCheck,
+    pub net_tension: BearingCheck,
+    pub shear_out: BearingCheck,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BushingServiceState {
     pub id: String,
@@ -448,6 +737,8 @@ pub struct BushingServiceState {
     pub projected_id: f64,
     pub id_change_from_free: f64,
     pub fit_class: String,
+    /// Lower of the housing and bushing margins in this state.
+    pub margin: f64,
     pub note: String,
 }
 
@@ -461,18 +752,24 @@ pub struct ServiceEnvelopeResult {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DutyScreenResult {
+    /// Family of the bushing material; `None` when its record names none and
+    /// the most conservative limits were used.
+    pub wear_family: Option<WearFamily>,
     pub load_spectrum: BushingLoadSpectrum,
     pub lubrication_mode: BushingLubricationMode,
     pub contamination_level: BushingContaminationLevel,
     pub specific_load_psi: f64,
     pub specific_load_mpa: f64,
     pub sliding_velocity_mps: f64,
+    /// MPa·m/s
     pub pv: f64,
+    /// MPa·m/s, derated for the entered service conditions
     pub pv_limit: f64,
     pub pv_utilization: f64,
     pub wear_risk: WearRisk,
     pub risk_score: f64,
     pub dominant_drivers: Vec<String>,
+    /// Operating hours to wear through the allowance; `None` without sliding.
     pub life_estimate_hours: Option<f64>,
 }
 
@@ -483,6 +780,28 @@ pub struct InstallForceBand {
     pub high: f64,
 }
 
+/// Shrink-fit (thermal-assist) installation plan. Temperature differentials are
+/// °F from the free-state reference; positive heats, negative chills.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThermalAssistPlan {
+    pub active: bool,
+    pub target_clearance: f64,
+    /// Housing heat needed with the bushing at reference temperature.
+    pub required_housing_delta_t_f: Option<f64>,
+    /// Bushing chill needed with the housing at reference temperature.
+    pub required_bushing_delta_t_f: Option<f64>,
+    pub housing_delta_t_f: f64,
+    pub bushing_delta_t_f: f64,
+    /// Diametral interference relieved by the assembly temperatures (inches).
+    pub assembly_thermal_delta: f64,
+    pub install_delta_effective: f64,
+    pub assembly_clearance: f64,
+    pub target_met: bool,
+    /// Minutes until the conditioned parts equalise enough for interference to return.
+    pub time_window_min: Option<f64>,
+    pub notes: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessReviewResult {
     pub route_id: BushingProcessRouteId,
@@ -495,19 +814,40 @@ pub struct ProcessReviewResult {
     pub assembly_thermal_assist_active: bool,
     pub install_force_band: InstallForceBand,
     pub removal_force: f64,
+    /// Diametral finish-ream stock needed to recover installed ID closure (inches).
+    pub required_finish_ream_allowance: f64,
+    pub thermal_plan: ThermalAssistPlan,
     pub notes: Vec<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalDecision {
+    #[default]
+    Pass,
+    Conditional,
+    Reject,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalFinding {
+    pub rule_id: String,
+    pub outcome: ApprovalDecision,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ApprovalReviewResult {
     pub standards_basis: BushingStandardsBasis,
     pub standards_revision: String,
     pub process_spec: String,
     pub criticality: BushingCriticality,
     pub approval_required: bool,
-    pub decision: String,
+    pub decision: ApprovalDecision,
     pub traceability_refs: Vec<String>,
     pub assumptions: Vec<String>,
+    /// Rules that fired, in evaluation order.
+    pub findings: Vec<ApprovalFinding>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -540,6 +880,56 @@ pub struct ToleranceResult {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitCornerResult {
+    pub id: String,
+    pub label: String,
+    pub bore_dia: f64,
+    pub od_bushing: f64,
+    pub interference: f64,
+    pub d_t: f64,
+    pub hoop: HoopResult,
+    pub physics: PhysicsResult,
+    pub min_margin: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RssResult {
+    pub interference_mean: f64,
+    pub interference_sigma: f64,
+    pub low: FitCornerResult,
+    pub high: FitCornerResult,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitPercentile {
+    pub percentile: f64,
+    pub interference: f64,
+    pub contact_pressure: f64,
+    pub margin_housing: f64,
+    pub margin_bushing: f64,
+    pub min_margin: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloResult {
+    pub samples: u32,
+    pub seed: u64,
+    pub clearance_fraction: f64,
+    pub negative_margin_fraction: f64,
+    pub percentiles: Vec<FitPercentile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitBandResult {
+    pub mode: BushingAnalysisMode,
+    pub corners: Vec<FitCornerResult>,
+    pub governing_corner_id: String,
+    pub min_margin: f64,
+    pub rss: Option<RssResult>,
+    pub monte_carlo: Option<MonteCarloResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct InputBasis {
     pub bore: String,
     pub id: String,
@@ -547,16 +937,39 @@ pub struct InputBasis {
     pub housing_width: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MeasuredPartSummary {
     pub applied: bool,
     pub basis: String,
     pub overrides: Vec<String>,
     pub notes: Vec<String>,
+    /// Largest diametral spread between orientations at one bore station (µm).
+    pub bore_roundness_um: Option<f64>,
+    /// Change in mean bore diameter per inch between the end stations.
+    pub bore_taper: Option<f64>,
+}
+
+/// Material resolved for one part of the joint.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MaterialSummary {
+    pub id: String,
+    pub name: String,
+    /// `library` or `inline`.
+    pub source: String,
+    pub basis: MaterialBasis,
+    /// False when the requested basis is not published and S-basis values were used.
+    pub basis_applied: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MaterialsUsed {
+    pub housing: MaterialSummary,
+    pub bushing: MaterialSummary,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BushingOutput {
+    pub units: UnitLabels,
     pub sleeve_wall: f64,
     pub neck_wall: Option<f64>,
     pub od_installed: f64,
@@ -564,18 +977,28 @@ pub struct BushingOutput {
     pub pressure: f64,
     pub lame: LameResult,
     pub hoop: HoopResult,
+    pub criteria: CriteriaResult,
     pub edge_distance: EdgeDistanceResult,
     pub governing: BushingCandidate,
     pub physics: PhysicsResult,
     pub geometry: GeometryResult,
+    pub flange: Option<FlangeResult>,
+    pub bearing: Option<BearingResult>,
     pub service_envelope: ServiceEnvelopeResult,
     pub duty_screen: DutyScreenResult,
     pub process: ProcessReviewResult,
     pub review: ApprovalReviewResult,
     pub input_basis: InputBasis,
+    pub materials: MaterialsUsed,
     pub measured_part_summary: MeasuredPartSummary,
     pub tolerance: ToleranceResult,
+    pub fit_band: Option<FitBandResult>,
     pub candidates: Vec<BushingCandidate>,
     pub warning_codes: Vec<BushingWarning>,
     pub warnings: Vec<String>,
+    /// The input as submitted, as a canonical document (see [`crate::canonical`]),
+    /// so a saved output re-solves to itself. Set by the `compute_bushing*`
+    /// entry points only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_input: Option<serde_json::Value>,
 }
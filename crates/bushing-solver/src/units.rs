@@ -0,0 +1,634 @@
+/// Imperial / metric unit handling. Metric inputs (mm, MPa, N, °C) are scaled
+/// to the solver's internal inches, ksi, lbf and °F on normalisation, and
+/// outputs are scaled back to the requested system with unit labels.
+use crate::types::*;
+
+pub const MM_PER_IN: f64 = 25.4;
+pub const MPA_PER_KSI: f64 = 6.894_757_293_168_361;
+pub const N_PER_LBF: f64 = 4.448_221_615_260_5;
+
+/// Parse a `units` value; anything unrecognised falls back to imperial.
+pub fn parse(value: &str) -> UnitSystem {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "metric" | "si" | "mm" => UnitSystem::Metric,
+        _                      => UnitSystem::Imperial,
+    }
+}
+
+pub fn labels(system: UnitSystem) -> UnitLabels {
+    let (length, area, stress, force, temperature) = match system {
+        UnitSystem::Imperial => ("in", "in^2", "ksi", "lbf", "°F"),
+        UnitSystem::Metric   => ("mm", "mm^2", "MPa", "N", "°C"),
+    };
+    UnitLabels {
+        system,
+        length: length.into(),
+        area: area.into(),
+        stress: stress.into(),
+        force: force.into(),
+        temperature: temperature.into(),
+    }
+}
+
+/// Physical dimension of a numeric `BushingInput` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Length,
+    Stress,
+    Force,
+    /// Temperature difference.
+    DeltaT,
+    /// Absolute temperature.
+    Temperature,
+    /// Angles, ratios, counts and fields with a fixed unit (µm, HRC, Hz).
+    None,
+}
+
+/// Dimension of the snake_case `BushingInput` field `field`; kept in step
+/// with `Convert for BushingInput` by a test.
+pub fn dimension(field: &str) -> Dimension {
+    match field {
+        "bore_dia" | "id_bushing" | "interference" | "housing_len" | "housing_width" | "edge_dist"
+        | "cs_dia" | "cs_depth" | "ext_cs_dia" | "ext_cs_depth" | "min_wall_straight" | "min_wall_neck"
+        | "bore_nominal" | "bore_tol_plus" | "bore_tol_minus" | "bore_lower" | "bore_upper"
+        | "interference_nominal" | "interference_tol_plus" | "interference_tol_minus"
+        | "interference_lower" | "interference_upper" | "hole_offset"
+        | "cs_depth_tol_plus" | "cs_depth_tol_minus" | "ext_cs_depth_tol_plus" | "ext_cs_depth_tol_minus"
+        | "flange_dia" | "flange_od" | "flange_thk"
+        | "assembly_clearance_target" | "finish_ream_allowance" | "wear_allowance" => Dimension::Length,
+        "load" | "axial_load" => Dimension::Force,
+        "d_t" | "d_t_std_dev" => Dimension::DeltaT,
+        "assembly_housing_temperature" | "assembly_bushing_temperature"
+        | "service_temperature_hot" | "service_temperature_cold" => Dimension::Temperature,
+        _ => Dimension::None,
+    }
+}
+
+/// Unit label of `dim` in `labels`; empty for dimensionless fields.
+pub fn label(labels: &UnitLabels, dim: Dimension) -> &str {
+    match dim {
+        Dimension::Length                           => &labels.length,
+        Dimension::Stress                           => &labels.stress,
+        Dimension::Force                            => &labels.force,
+        Dimension::DeltaT | Dimension::Temperature  => &labels.temperature,
+        Dimension::None                             => "",
+    }
+}
+
+/// Linear factors between two unit systems; absolute temperatures map as
+/// `(t - t_from) * delta_t + t_to`.
+#[derive(Debug, Clone, Copy)]
+pub struct Scale {
+    pub length: f64,
+    pub stress: f64,
+    pub force: f64,
+    pub delta_t: f64,
+    t_from: f64,
+    t_to: f64,
+}
+
+impl Scale {
+    const IDENTITY: Scale = Scale { length: 1.0, stress: 1.0, force: 1.0, delta_t: 1.0, t_from: 0.0, t_to: 0.0 };
+
+    /// From `system` to the internal imperial units.
+    pub fn to_internal(system: UnitSystem) -> Self {
+        match system {
+            UnitSystem::Imperial => Self::IDENTITY,
+            UnitSystem::Metric   => Scale {
+                length: 1.0 / MM_PER_IN,
+                stress: 1.0 / MPA_PER_KSI,
+                force: 1.0 / N_PER_LBF,
+                delta_t: 1.8,
+                t_from: 0.0,
+                t_to: 32.0,
+            },
+        }
+    }
+
+    /// From the internal imperial units to `system`.
+    pub fn from_internal(system: UnitSystem) -> Self {
+        match system {
+            UnitSystem::Imperial => Self::IDENTITY,
+            UnitSystem::Metric   => Scale {
+                length: MM_PER_IN,
+                stress: MPA_PER_KSI,
+                force: N_PER_LBF,
+                delta_t: 1.0 / 1.8,
+                t_from: 32.0,
+                t_to: 0.0,
+            },
+        }
+    }
+
+    /// `x` of dimension `dim` in the target system.
+    pub fn value(&self, dim: Dimension, x: f64) -> f64 {
+        match dim {
+            Dimension::Temperature => (x - self.t_from) * self.delta_t + self.t_to,
+            _                      => x * self.factor(dim),
+        }
+    }
+
+    /// Linear part of the map for `dim`, e.g. for differences and derivatives.
+    pub fn factor(&self, dim: Dimension) -> f64 {
+        match dim {
+            Dimension::Length                          => self.length,
+            Dimension::Stress                          => self.stress,
+            Dimension::Force                           => self.force,
+            Dimension::DeltaT | Dimension::Temperature => self.delta_t,
+            Dimension::None                            => 1.0,
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.length == 1.0 && self.stress == 1.0 && self.force == 1.0 && self.delta_t == 1.0
+    }
+
+    fn l(&self, x: &mut f64) {
+        *x *= self.length;
+    }
+    fn lo(&self, x: &mut Option<f64>) {
+        if let Some(v) = x {
+            self.l(v);
+        }
+    }
+    fn a(&self, x: &mut f64) {
+        *x *= self.length * self.length;
+    }
+    fn s(&self, x: &mut f64) {
+        *x *= self.stress;
+    }
+    fn f(&self, x: &mut f64) {
+        *x *= self.force;
+    }
+    fn fo(&self, x: &mut Option<f64>) {
+        if let Some(v) = x {
+            self.f(v);
+        }
+    }
+    /// Compliance terms, length per stress.
+    fn c(&self, x: &mut f64) {
+        *x *= self.length / self.stress;
+    }
+    fn dt(&self, x: &mut f64) {
+        *x *= self.delta_t;
+    }
+    fn to(&self, x: &mut Option<f64>) {
+        if let Some(v) = x {
+            *v = (*v - self.t_from) * self.delta_t + self.t_to;
+        }
+    }
+}
+
+/// Dimensional values scaled in place by a [`Scale`].
+pub trait Convert {
+    fn convert(&mut self, k: &Scale);
+}
+
+impl<T: Convert> Convert for Option<T> {
+    fn convert(&mut self, k: &Scale) {
+        if let Some(x) = self {
+            x.convert(k);
+        }
+    }
+}
+
+impl<T: Convert> Convert for Vec<T> {
+    fn convert(&mut self, k: &Scale) {
+        self.iter_mut().for_each(|x| x.convert(k));
+    }
+}
+
+// ── Inputs ────────────────────────────────────────────────────────────────────
+
+impl Convert for CountersinkInput {
+    fn convert(&mut self, k: &Scale) {
+        k.lo(&mut self.dia);
+        k.lo(&mut self.depth);
+    }
+}
+
+impl Convert for MeasuredDimension {
+    fn convert(&mut self, k: &Scale) {
+        k.lo(&mut self.actual);
+        k.lo(&mut self.tol_plus);
+        k.lo(&mut self.tol_minus);
+        for r in &mut self.readings {
+            k.l(&mut r.dia);
+            k.lo(&mut r.station);
+        }
+    }
+}
+
+impl Convert for MeasuredPart {
+    fn convert(&mut self, k: &Scale) {
+        self.bore.convert(k);
+        self.od.convert(k);
+        self.id.convert(k);
+        k.lo(&mut self.edge_dist);
+        k.lo(&mut self.housing_width);
+    }
+}
+
+impl Convert for BushingInput {
+    fn convert(&mut self, k: &Scale) {
+        for x in [
+            &mut self.bore_dia, &mut self.id_bushing, &mut self.interference,
+            &mut self.housing_len, &mut self.housing_width, &mut self.edge_dist,
+            &mut self.cs_dia, &mut self.cs_depth, &mut self.ext_cs_dia, &mut self.ext_cs_depth,
+            &mut self.min_wall_straight, &mut self.min_wall_neck,
+        ] {
+            k.l(x);
+        }
+        for x in [
+            &mut self.bore_nominal, &mut self.bore_tol_plus, &mut self.bore_tol_minus,
+            &mut self.bore_lower, &mut self.bore_upper,
+            &mut self.interference_nominal, &mut self.interference_tol_plus, &mut self.interference_tol_minus,
+            &mut self.interference_lower, &mut self.interference_upper,
+            &mut self.interference_policy.max_bore_nominal_shift,
+            &mut self.bore_capability.min_achievable_tol_width, &mut self.bore_capability.max_recommended_tol_width,
+            &mut self.hole_offset,
+            &mut self.cs_depth_tol_plus, &mut self.cs_depth_tol_minus,
+            &mut self.ext_cs_depth_tol_plus, &mut self.ext_cs_depth_tol_minus,
+            &mut self.flange_dia, &mut self.flange_od, &mut self.flange_thk,
+            &mut self.assembly_clearance_target, &mut self.finish_ream_allowance, &mut self.wear_allowance,
+        ] {
+            k.lo(x);
+        }
+        self.id_cs.convert(k);
+        self.od_cs.convert(k);
+        self.measured_part.convert(k);
+        k.fo(&mut self.load);
+        k.fo(&mut self.axial_load);
+        k.dt(&mut self.d_t);
+        if let Some(sd) = &mut self.d_t_std_dev {
+            k.dt(sd);
+        }
+        for t in [
+            &mut self.assembly_housing_temperature, &mut self.assembly_bushing_temperature,
+            &mut self.service_temperature_hot, &mut self.service_temperature_cold,
+        ] {
+            k.to(t);
+        }
+    }
+}
+
+// ── Outputs ───────────────────────────────────────────────────────────────────
+
+impl Convert for CsSolvedDim {
+    fn convert(&mut self, k: &Scale) {
+        k.l(&mut self.dia);
+        k.l(&mut self.depth);
+    }
+}
+
+impl Convert for StressRegion {
+    fn convert(&mut self, k: &Scale) {
+        k.l(&mut self.inner_radius);
+        k.l(&mut self.outer_radius);
+        for p in &mut self.samples {
+            k.l(&mut p.r);
+            for x in [&mut p.sigma_r, &mut p.sigma_theta, &mut p.sigma_axial] {
+                k.s(x);
+            }
+        }
+        let b = &mut self.boundary;
+        for x in [
+            &mut b.sigma_r_inner, &mut b.sigma_r_outer, &mut b.sigma_theta_inner, &mut b.sigma_theta_outer,
+            &mut b.sigma_axial_inner, &mut b.sigma_axial_outer, &mut b.max_abs_hoop, &mut b.max_abs_axial,
+        ] {
+            k.s(x);
+        }
+        k.l(&mut b.max_abs_hoop_at);
+        k.l(&mut b.max_abs_axial_at);
+    }
+}
+
+impl Convert for LameResult {
+    fn convert(&mut self, k: &Scale) {
+        for x in [
+            &mut self.delta_total, &mut self.delta_thermal, &mut self.delta_user, &mut self.bore_dia,
+            &mut self.id_bushing, &mut self.effective_od_housing, &mut self.d_equivalent,
+            &mut self.w_eff, &mut self.e_eff,
+        ] {
+            k.l(x);
+        }
+        k.c(&mut self.term_b);
+        k.c(&mut self.term_h);
+        self.field.bushing.convert(k);
+        self.field.housing.convert(k);
+    }
+}
+
+impl Convert for HoopResult {
+    fn convert(&mut self, k: &Scale) {
+        for x in [
+            &mut self.housing_sigma, &mut self.bushing_sigma, &mut self.housing_sigma_eq,
+            &mut self.bushing_sigma_eq, &mut self.ligament_sigma,
+        ] {
+            k.s(x);
+        }
+        k.lo(&mut self.ed_required_ligament);
+    }
+}
+
+impl Convert for RegionCriteria {
+    fn convert(&mut self, k: &Scale) {
+        k.s(&mut self.allowable);
+        for m in &mut self.margins {
+            k.s(&mut m.max_stress);
+            k.l(&mut m.at_radius);
+        }
+    }
+}
+
+impl Convert for PhysicsResult {
+    fn convert(&mut self, k: &Scale) {
+        for x in [&mut self.delta_effective, &mut self.install_delta_effective, &mut self.assembly_thermal_delta] {
+            k.l(x);
+        }
+        k.lo(&mut self.ed_min_coupled);
+        for x in [
+            &mut self.contact_pressure, &mut self.install_contact_pressure, &mut self.stress_hoop_housing,
+            &mut self.stress_hoop_bushing, &mut self.stress_axial_housing, &mut self.stress_axial_bushing,
+        ] {
+            k.s(x);
+        }
+        k.f(&mut self.install_force);
+        k.f(&mut self.retained_install_force);
+    }
+}
+
+impl Convert for FlangeResult {
+    fn convert(&mut self, k: &Scale) {
+        for x in [&mut self.flange_od, &mut self.flange_thk, &mut self.neck_wall] {
+            k.l(x);
+        }
+        k.f(&mut self.axial_load);
+        k.a(&mut self.bearing_area);
+        k.a(&mut self.shear_area);
+        for x in [&mut self.bearing_stress, &mut self.bearing_allowable, &mut self.shear_stress, &mut self.shear_allowable] {
+            k.s(x);
+        }
+    }
+}
+
+impl Convert for BearingCheck {
+    fn convert(&mut self, k: &Scale) {
+        k.a(&mut self.area);
+        k.s(&mut self.stress);
+        k.s(&mut self.allowable);
+    }
+}
+
+impl Convert for BearingResult {
+    fn convert(&mut self, k: &Scale) {
+        k.f(&mut self.load);
+        k.f(&mut self.edge_load);
+        for c in [&mut self.pin_bearing, &mut self.bushing_housing_bearing, &mut self.net_tension, &mut self.shear_out] {
+            c.convert(k);
+        }
+    }
+}
+
+impl Convert for BushingServiceState {
+    fn convert(&mut self, k: &Scale) {
+        for x in [&mut self.effective_interference, &mut self.projected_id, &mut self.id_change_from_free] {
+            k.l(x);
+        }
+        k.s(&mut self.contact_pressure);
+    }
+}
+
+impl Convert for ProcessReviewResult {
+    fn convert(&mut self, k: &Scale) {
+        let band = &mut self.install_force_band;
+        for x in [&mut band.low, &mut band.nominal, &mut band.high, &mut self.removal_force] {
+            k.f(x);
+        }
+        k.l(&mut self.required_finish_ream_allowance);
+        let plan = &mut self.thermal_plan;
+        for x in [&mut plan.target_clearance, &mut plan.assembly_thermal_delta, &mut plan.install_delta_effective, &mut plan.assembly_clearance] {
+            k.l(x);
+        }
+    }
+}
+
+impl Convert for ToleranceRange {
+    fn convert(&mut self, k: &Scale) {
+        for x in [&mut self.lower, &mut self.upper, &mut self.nominal, &mut self.tol_plus, &mut self.tol_minus] {
+            k.l(x);
+        }
+    }
+}
+
+impl Convert for ToleranceResult {
+    fn convert(&mut self, k: &Scale) {
+        let e = &mut self.enforcement;
+        for x in [
+            &mut e.required_bore_tol_width, &mut e.available_bore_tol_width, &mut e.target_interference_width,
+            &mut e.lower_violation, &mut e.upper_violation, &mut e.bore_nominal_shift_applied,
+        ] {
+            k.l(x);
+        }
+        for r in [&mut self.bore, &mut self.interference_target, &mut self.od_bushing, &mut self.achieved_interference] {
+            r.convert(k);
+        }
+        for r in [&mut self.cs_internal_dia, &mut self.cs_internal_depth, &mut self.cs_external_dia, &mut self.cs_external_depth] {
+            r.convert(k);
+        }
+    }
+}
+
+impl Convert for FitCornerResult {
+    fn convert(&mut self, k: &Scale) {
+        for x in [&mut self.bore_dia, &mut self.od_bushing, &mut self.interference] {
+            k.l(x);
+        }
+        k.dt(&mut self.d_t);
+        self.hoop.convert(k);
+        self.physics.convert(k);
+    }
+}
+
+impl Convert for FitBandResult {
+    fn convert(&mut self, k: &Scale) {
+        self.corners.convert(k);
+        if let Some(rss) = &mut self.rss {
+            k.l(&mut rss.interference_mean);
+            k.l(&mut rss.interference_sigma);
+            rss.low.convert(k);
+            rss.high.convert(k);
+        }
+        if let Some(mc) = &mut self.monte_carlo {
+            for p in &mut mc.percentiles {
+                k.l(&mut p.interference);
+                k.s(&mut p.contact_pressure);
+            }
+        }
+    }
+}
+
+impl Convert for BushingOutput {
+    fn convert(&mut self, k: &Scale) {
+        k.l(&mut self.sleeve_wall);
+        k.lo(&mut self.neck_wall);
+        k.l(&mut self.od_installed);
+        self.cs_solved.id.convert(k);
+        self.cs_solved.od.convert(k);
+        k.s(&mut self.pressure);
+        self.lame.convert(k);
+        self.hoop.convert(k);
+        self.criteria.housing.convert(k);
+        self.criteria.bushing.convert(k);
+        let ed = &mut self.edge_distance;
+        k.lo(&mut ed.ed_min_strength);
+        for x in [&mut ed.ed_min_sequence, &mut ed.ed_actual] {
+            k.l(x);
+        }
+        self.physics.convert(k);
+        let g = &mut self.geometry;
+        for x in [&mut g.od_bushing, &mut g.wall_straight, &mut g.wall_neck] {
+            k.l(x);
+        }
+        g.cs_internal.convert(k);
+        g.cs_external.convert(k);
+        self.flange.convert(k);
+        self.bearing.convert(k);
+        self.service_envelope.states.convert(k);
+        self.process.convert(k);
+        self.tolerance.convert(k);
+        self.fit_band.convert(k);
+    }
+}
+
+/// Internal-unit values written into messages in the reported system, with
+/// their unit labels.
+pub struct Show {
+    k: Scale,
+    labels: UnitLabels,
+}
+
+impl Show {
+    pub fn new(system: UnitSystem) -> Self {
+        Self { k: Scale::from_internal(system), labels: labels(system) }
+    }
+
+    pub fn quantity(&self, dim: Dimension, x: f64, decimals: usize) -> String {
+        format!("{:.*} {}", decimals, self.k.value(dim, x), label(&self.labels, dim))
+    }
+
+    pub fn length(&self, x: f64, decimals: usize) -> String {
+        self.quantity(Dimension::Length, x, decimals)
+    }
+
+    pub fn temperature(&self, t: f64, decimals: usize) -> String {
+        self.quantity(Dimension::Temperature, t, decimals)
+    }
+
+    /// Signed temperature difference.
+    pub fn delta_t(&self, d_t: f64, decimals: usize) -> String {
+        format!("{:+.*} {}", decimals, self.k.value(Dimension::DeltaT, d_t), self.labels.temperature)
+    }
+
+    pub fn length_label(&self) -> &str {
+        &self.labels.length
+    }
+}
+
+/// Re-express a solved output (internal units) in `system`, with labels.
+pub fn to_system(out: &mut BushingOutput, system: UnitSystem) {
+    let k = Scale::from_internal(system);
+    if !k.is_identity() {
+        out.convert(&k);
+    }
+    out.units = labels(system);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{input_with, metric, solve_with};
+    use serde_json::json;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn imperial_known_result_and_pressure_labels() {
+        let out = solve_with(json!({}));
+        assert_eq!(out.units.system, UnitSystem::Imperial);
+        assert_eq!((out.units.length.as_str(), out.units.stress.as_str()), ("in", "ksi"));
+        assert!(close(out.pressure, 15.765553638909543));
+        assert_eq!(out.lame.pressure_ksi, out.pressure);
+        assert!(close(out.lame.pressure_psi, out.pressure * 1000.0));
+    }
+
+    #[test]
+    fn metric_input_matches_imperial_solve() {
+        let imp = solve_with(json!({ "load": 3000.0, "dT": 90.0, "serviceTemperatureHot": 250.0 }));
+        let si  = solve_with(metric(json!({ "load": 3000.0 * N_PER_LBF, "dT": 50.0, "serviceTemperatureHot": 121.0 + 1.0 / 9.0 })));
+        assert_eq!(si.units, labels(UnitSystem::Metric));
+        assert!(close(si.pressure, imp.pressure * MPA_PER_KSI));
+        assert!(close(si.lame.bore_dia, 12.7));
+        assert!(close(si.physics.install_force, imp.physics.install_force * N_PER_LBF));
+        assert!(close(si.bearing.as_ref().unwrap().pin_bearing.area, imp.bearing.as_ref().unwrap().pin_bearing.area * 645.16));
+        assert!(close(si.hoop.housing_ms, imp.hoop.housing_ms));
+        assert_eq!(si.governing.name, imp.governing.name);
+        // Suffix-named fields keep their unit in either system
+        assert!(close(si.lame.pressure_ksi, imp.lame.pressure_ksi));
+        let hot = |o: &BushingOutput| o.service_envelope.states.iter().find(|s| s.id == "hot").unwrap().margin;
+        assert!(close(hot(&si), hot(&imp)));
+    }
+
+    #[test]
+    fn output_round_trips_through_metric() {
+        let imp = solve_with(json!({ "load": 3000.0, "analysisMode": "worst_case", "boreTolPlus": 0.0005 }));
+        let mut out = imp.clone();
+        out.convert(&Scale::from_internal(UnitSystem::Metric));
+        out.convert(&Scale::to_internal(UnitSystem::Metric));
+        let (a, b) = (serde_json::to_value(&imp).unwrap(), serde_json::to_value(&out).unwrap());
+        assert_round_trip(&a, &b);
+
+        let mut input = input_with(json!({ "serviceTemperatureCold": -65.0 }));
+        let orig = serde_json::to_value(&input).unwrap();
+        input.convert(&Scale::from_internal(UnitSystem::Metric));
+        assert!(close(input.service_temperature_cold.unwrap(), -53.0 - 8.0 / 9.0));
+        input.convert(&Scale::to_internal(UnitSystem::Metric));
+        assert_round_trip(&orig, &serde_json::to_value(&input).unwrap());
+    }
+
+    #[test]
+    fn field_dimensions_match_the_input_conversion() {
+        let input  = input_with(json!({}));
+        let k      = Scale::from_internal(UnitSystem::Metric);
+        let fields = serde_json::to_value(&input).unwrap();
+        for (field, _) in fields.as_object().unwrap().iter().filter(|(_, v)| v.is_number() || v.is_null()) {
+            let mut probe = input.clone();
+            if crate::sweep::set_field(&mut probe, field, 3.0).is_err() {
+                continue;
+            }
+            probe.convert(&k);
+            let got = serde_json::to_value(&probe).unwrap()[field].as_f64().unwrap();
+            assert!(close(got, k.value(dimension(field), 3.0)), "{field}: {got}");
+        }
+        let show = Show::new(UnitSystem::Metric);
+        assert_eq!(show.length(0.5, 2), "12.70 mm");
+        assert_eq!((show.temperature(212.0, 0), show.delta_t(-18.0, 0)), ("100 °C".into(), "-10 °C".into()));
+    }
+
+    fn assert_round_trip(a: &serde_json::Value, b: &serde_json::Value) {
+        match (a, b) {
+            (serde_json::Value::Number(x), serde_json::Value::Number(y)) => {
+                assert!(close(y.as_f64().unwrap(), x.as_f64().unwrap()), "{x} != {y}");
+            }
+            (serde_json::Value::Object(x), serde_json::Value::Object(y)) => {
+                x.iter().for_each(|(k, v)| assert_round_trip(v, &y[k]));
+            }
+            (serde_json::Value::Array(x), serde_json::Value::Array(y)) => {
+                x.iter().zip(y).for_each(|(v, w)| assert_round_trip(v, w));
+            }
+            _ => assert_eq!(a, b),
+        }
+    }
+}